@@ -1,15 +1,8 @@
-use hex;
-
 use std::io::Write;
 use std::io::{BufRead, BufReader};
 use std::{env, error, fmt, fs};
 
-use classic_mceliece_rust::{crypto_kem_dec, crypto_kem_enc, crypto_kem_keypair};
-use classic_mceliece_rust::{AesState, RNGState};
-use classic_mceliece_rust::{
-    CRYPTO_BYTES, CRYPTO_CIPHERTEXTBYTES, CRYPTO_PRIMITIVE, CRYPTO_PUBLICKEYBYTES,
-    CRYPTO_SECRETKEYBYTES,
-};
+use classic_mceliece_rust::{AesState, Kem, RNGState, Variant};
 
 const KATNUM: usize = 100;
 
@@ -30,10 +23,10 @@ type R = Result<(), Box<dyn error::Error>>;
 struct Testcase {
     count: usize,
     seed: [u8; 48],
-    pk: [u8; CRYPTO_PUBLICKEYBYTES],
-    sk: [u8; CRYPTO_SECRETKEYBYTES],
-    ct: [u8; CRYPTO_CIPHERTEXTBYTES],
-    ss: [u8; CRYPTO_BYTES],
+    pk: Vec<u8>,
+    sk: Vec<u8>,
+    ct: Vec<u8>,
+    ss: Vec<u8>,
 }
 
 fn is_zero(x: &[u8]) -> bool {
@@ -47,31 +40,28 @@ fn is_zero(x: &[u8]) -> bool {
 }
 
 impl Testcase {
-    fn new() -> Testcase {
+    fn new(variant: Variant) -> Testcase {
         Testcase {
             count: 0,
             seed: [0u8; 48],
-            pk: [0u8; CRYPTO_PUBLICKEYBYTES],
-            sk: [0u8; CRYPTO_SECRETKEYBYTES],
-            ct: [0u8; CRYPTO_CIPHERTEXTBYTES],
-            ss: [0u8; CRYPTO_BYTES],
+            pk: vec![0u8; variant.public_key_bytes()],
+            sk: vec![0u8; variant.secret_key_bytes()],
+            ct: vec![0u8; variant.ciphertext_bytes()],
+            ss: vec![0u8; variant.shared_secret_bytes()],
         }
     }
 
-    fn with_seed(count: usize, seed: &[u8; 48]) -> Testcase {
+    fn with_seed(variant: Variant, count: usize, seed: &[u8; 48]) -> Testcase {
         Testcase {
-            count,
             seed: *seed,
-            pk: [0u8; CRYPTO_PUBLICKEYBYTES],
-            sk: [0u8; CRYPTO_SECRETKEYBYTES],
-            ct: [0u8; CRYPTO_CIPHERTEXTBYTES],
-            ss: [0u8; CRYPTO_BYTES],
+            count,
+            ..Testcase::new(variant)
         }
     }
 
     fn write_to_file(&self, fd: &mut fs::File) -> R {
         let repr_bytes = |bytes: &[u8]| -> String {
-            if is_zero(&bytes) {
+            if is_zero(bytes) {
                 "".to_string()
             } else {
                 format!(" {}", hex::encode_upper(bytes))
@@ -116,10 +106,10 @@ impl Testcase {
         match name {
             "count" => self.count = value.parse::<usize>()?,
             "seed" => hex::decode_to_slice(value, &mut self.seed as &mut [u8])?,
-            "pk" => hex::decode_to_slice(value, &mut self.pk as &mut [u8])?,
-            "sk" => hex::decode_to_slice(value, &mut self.sk as &mut [u8])?,
-            "ct" => hex::decode_to_slice(value, &mut self.ct as &mut [u8])?,
-            "ss" => hex::decode_to_slice(value, &mut self.ss as &mut [u8])?,
+            "pk" => hex::decode_to_slice(value, &mut self.pk[..])?,
+            "sk" => hex::decode_to_slice(value, &mut self.sk[..])?,
+            "ct" => hex::decode_to_slice(value, &mut self.ct[..])?,
+            "ss" => hex::decode_to_slice(value, &mut self.ss[..])?,
             _ => return err(&format!("assignment to unknown key '{}'", name)),
         };
 
@@ -145,7 +135,7 @@ impl fmt::Display for Testcase {
         //   to abstract Testcase.write_to_file(…) for stdout AND files.
         //   As a result, I decided to duplicate the code.
         let repr_bytes = |bytes: &[u8]| -> String {
-            if is_zero(&bytes) {
+            if is_zero(bytes) {
                 "".to_string()
             } else {
                 format!(" {}", hex::encode_upper(bytes))
@@ -161,19 +151,19 @@ impl fmt::Display for Testcase {
     }
 }
 
-fn create_request_file(filepath: &str, rng: &mut impl RNGState) -> R {
+fn create_request_file(variant: Variant, filepath: &str, rng: &mut impl RNGState) -> R {
     let mut fd = fs::File::create(filepath)?;
 
     // initialize RNG
     let mut entropy_input = [0u8; 48];
-    for i in 0..48 {
-        entropy_input[i] = i as u8;
+    for (i, e) in entropy_input.iter_mut().enumerate() {
+        *e = i as u8;
     }
     rng.randombytes_init(entropy_input);
 
     // create KATNUM testcase seeds
     for t in 0..KATNUM {
-        let mut tc = Testcase::new();
+        let mut tc = Testcase::new(variant);
         tc.count = t;
         rng.randombytes(&mut tc.seed)?;
 
@@ -183,30 +173,31 @@ fn create_request_file(filepath: &str, rng: &mut impl RNGState) -> R {
     Ok(())
 }
 
-fn create_response_file(filepath: &str, rng: &mut impl RNGState) -> R {
+fn create_response_file(kem: &dyn Kem, filepath: &str, rng: &mut impl RNGState) -> R {
+    let variant = kem.variant();
     let mut fd = fs::File::create(filepath)?;
-    writeln!(&mut fd, "# kem/{}\n", CRYPTO_PRIMITIVE)?;
+    writeln!(&mut fd, "# kem/{}\n", variant)?;
 
     // initialize RNG
     let mut entropy_input = [0u8; 48];
-    for i in 0..48 {
-        entropy_input[i] = i as u8;
+    for (i, e) in entropy_input.iter_mut().enumerate() {
+        *e = i as u8;
     }
     rng.randombytes_init(entropy_input);
 
     // create KATNUM testcase seeds
     for t in 0..KATNUM {
-        let mut tc = Testcase::new();
+        let mut tc = Testcase::new(variant);
         tc.count = t;
         rng.randombytes(&mut tc.seed)?;
 
         let mut tc_rng = AesState::new();
         tc_rng.randombytes_init(tc.seed);
 
-        crypto_kem_keypair(&mut tc.pk, &mut tc.sk, &mut tc_rng)?;
-        crypto_kem_enc(&mut tc.ct, &mut tc.ss, &tc.pk, &mut tc_rng)?;
-        let mut ss = [0u8; CRYPTO_BYTES];
-        crypto_kem_dec(&mut ss, &tc.ct, &tc.sk)?;
+        kem.keypair(&mut tc.pk, &mut tc.sk, &mut tc_rng)?;
+        kem.encapsulate(&mut tc.ct, &mut tc.ss, &tc.pk, &mut tc_rng)?;
+        let mut ss = vec![0u8; variant.shared_secret_bytes()];
+        kem.decapsulate(&mut ss, &tc.ct, &tc.sk)?;
 
         assert_eq!(tc.ss, ss);
         tc.write_to_file(&mut fd)?;
@@ -215,27 +206,28 @@ fn create_response_file(filepath: &str, rng: &mut impl RNGState) -> R {
     Ok(())
 }
 
-fn verify(filepath: &str) -> R {
+fn verify(kem: &dyn Kem, filepath: &str) -> R {
+    let variant = kem.variant();
     let fd = fs::File::open(filepath)?;
     let mut reader = BufReader::new(fd);
     let mut rng = AesState::new();
 
     // first record in a response file is empty (e.g. “# ntruhps2048509\n”)
     // hence, skip it
-    let mut expected = Testcase::new();
+    let mut expected = Testcase::new(variant);
     expected.read_from_file(&mut reader)?;
 
     // create KATNUM testcase seeds
     for t in 0..KATNUM {
-        let mut expected = Testcase::new();
+        let mut expected = Testcase::new(variant);
         expected.read_from_file(&mut reader)?;
 
         rng.randombytes_init(expected.seed);
 
-        let mut actual = Testcase::with_seed(t, &expected.seed);
-        crypto_kem_keypair(&mut actual.pk, &mut actual.sk, &mut rng)?;
-        crypto_kem_enc(&mut actual.ct, &mut actual.ss, &actual.pk, &mut rng)?;
-        crypto_kem_dec(&mut actual.ss, &actual.ct, &actual.sk)?;
+        let mut actual = Testcase::with_seed(variant, t, &expected.seed);
+        kem.keypair(&mut actual.pk, &mut actual.sk, &mut rng)?;
+        kem.encapsulate(&mut actual.ct, &mut actual.ss, &actual.pk, &mut rng)?;
+        kem.decapsulate(&mut actual.ss, &actual.ct, &actual.sk)?;
 
         //assert_eq!(expected, actual);
         assert_eq!(
@@ -271,29 +263,35 @@ fn verify(filepath: &str) -> R {
 fn main() -> R {
     let mut args = env::args();
     match args.len() {
-        1 => {
-            eprintln!("usage: ./PQCgenKAT_kem <request:filepath> <response:filepath>");
+        1 | 2 => {
+            eprintln!("usage: ./PQCgenKAT_kem <variant> <request:filepath> <response:filepath>");
             eprintln!("  generate a request and response file\n");
-            eprintln!("usage: ./PQCgenKAT_kem <response:filepath>");
+            eprintln!("usage: ./PQCgenKAT_kem <variant> <response:filepath>");
             eprintln!("  verify the given response file\n");
+            eprintln!("where <variant> is one of:");
+            for variant in Variant::ALL {
+                eprintln!("  {}", variant);
+            }
             panic!("wrong number of arguments");
         }
 
-        2 => {
+        3 => {
             args.next().unwrap();
+            let kem = Variant::from_round4_name(&args.next().unwrap())?.kem();
             let rsp_file = args.next().unwrap();
-            verify(&rsp_file)?;
+            verify(kem, &rsp_file)?;
 
             println!("Verification successful.");
         }
 
-        3 => {
+        4 => {
             args.next().unwrap();
+            let kem = Variant::from_round4_name(&args.next().unwrap())?.kem();
             let req_file = args.next().unwrap();
             let rsp_file = args.next().unwrap();
 
-            create_request_file(&req_file, &mut AesState::new())?;
-            create_response_file(&rsp_file, &mut AesState::new())?;
+            create_request_file(kem.variant(), &req_file, &mut AesState::new())?;
+            create_response_file(kem, &rsp_file, &mut AesState::new())?;
 
             println!("request and response file created.");
         }
@@ -0,0 +1,49 @@
+//! Minimal `no_std` binary running key generation, encapsulation and decapsulation.
+//! It exists to prove that the crate builds without `std` and without `alloc`:
+//!
+//! ```bash
+//! $ cargo build --target thumbv7em-none-eabi
+//! $ cargo build --target x86_64-unknown-none
+//! ```
+
+#![no_std]
+#![no_main]
+
+use core::panic::PanicInfo;
+
+use classic_mceliece_rust::mceliece348864::{crypto_kem_dec, crypto_kem_enc, crypto_kem_keypair};
+use classic_mceliece_rust::mceliece348864::{
+    CRYPTO_BYTES, CRYPTO_CIPHERTEXTBYTES, CRYPTO_PUBLICKEYBYTES, CRYPTO_SECRETKEYBYTES,
+};
+use classic_mceliece_rust::{AesState, Error, RNGState};
+
+fn run() -> Result<bool, Error> {
+    let mut rng = AesState::new();
+    rng.randombytes_init([0u8; 48]);
+
+    let mut pk = [0u8; CRYPTO_PUBLICKEYBYTES];
+    let mut sk = [0u8; CRYPTO_SECRETKEYBYTES];
+    let mut ct = [0u8; CRYPTO_CIPHERTEXTBYTES];
+    let mut ss_alice = [0u8; CRYPTO_BYTES];
+    let mut ss_bob = [0u8; CRYPTO_BYTES];
+
+    crypto_kem_keypair(&mut pk, &mut sk, &mut rng)?;
+    crypto_kem_enc(&mut ct, &mut ss_bob, &pk, &mut rng)?;
+    crypto_kem_dec(&mut ss_alice, &ct, &sk)?;
+
+    Ok(ss_alice == ss_bob)
+}
+
+#[no_mangle]
+pub extern "C" fn _start() -> ! {
+    let _ = run();
+
+    #[allow(clippy::empty_loop)]
+    loop {}
+}
+
+#[panic_handler]
+fn panic(_info: &PanicInfo) -> ! {
+    #[allow(clippy::empty_loop)]
+    loop {}
+}
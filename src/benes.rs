@@ -4,13 +4,12 @@
 //! “McBits Revisited” by Tung Chou (2017)
 //! <https://eprint.iacr.org/2017/793.pdf>
 
-use crate::gf::Gf;
-use crate::macros::sub;
-use crate::params::SYS_N;
-use crate::params::{COND_BYTES, GFBITS};
+use super::gf::Gf;
+use super::params::SYS_N;
+use super::params::{COND_BYTES, GFBITS};
+use super::util;
+use crate::macros::{sub, zeroize};
 use crate::transpose;
-use crate::util;
-use std::error;
 
 /// Layers of the Beneš network. The required size of `data` and `bits` depends on the value `lgs`.
 /// NOTE const expressions are not sophisticated enough in rust yet to represent this relationship.
@@ -22,7 +21,7 @@ use std::error;
 /// | 6 | 128 | 64 |
 /// | 5 | 64 | 32 |
 /// | 4 | 64 | 32 |
-#[cfg(any(feature = "mceliece348864", feature = "mceliece348864f"))]
+#[allow(clippy::identity_op)]
 fn layer(data: &mut [u64], bits: &[u64], lgs: usize) {
     let mut index = 0;
 
@@ -44,7 +43,7 @@ fn layer(data: &mut [u64], bits: &[u64], lgs: usize) {
 
 /// Inner layers of the Beneš network. The required size of `data` and `bits` depends on the value `lgs`.
 /// `data[0]`, `data[1]` and `bits` must have the same length; namely `2^(lgs + 1)` with `lgs ≥ 5`.
-#[cfg(not(any(feature = "mceliece348864", feature = "mceliece348864f")))]
+#[allow(clippy::identity_op)]
 fn layer_in<const L: usize>(data: &mut [[u64; L]; 2], bits: &[u64; L], lgs: usize) {
     let mut d: u64;
     let mut index = 0;
@@ -88,7 +87,7 @@ fn layer_in<const L: usize>(data: &mut [[u64; L]; 2], bits: &[u64; L], lgs: usiz
 /// the entire array `data`, this implementation always considers `data` as two-dimensional array.
 /// The C implementation uses 128 as upper bound (because the array contains 128 elements),
 /// but this implementation has 64 elements per subarray and needs case distinctions at different places.
-#[cfg(not(any(feature = "mceliece348864", feature = "mceliece348864f")))]
+#[allow(clippy::identity_op)]
 fn layer_ex(data: &mut [[u64; 64]; 2], bits: &[u64], lgs: usize) {
     let mut data0_idx = 0;
     let mut data1_idx = 32;
@@ -136,12 +135,16 @@ fn layer_ex(data: &mut [[u64; 64]; 2], bits: &[u64], lgs: usize) {
 /// Here, `r` is a sequence of bits to be permuted.
 /// `bits` defines the condition bits configuring the Beneš network and
 /// `rev` toggles between normal application (0) or its inverse (!0).
-#[cfg(any(feature = "mceliece348864", feature = "mceliece348864f"))]
-fn apply_benes(
-    r: &mut [u8; 512],
-    bits: &[u8; COND_BYTES],
-    rev: usize,
-) -> Result<(), Box<dyn error::Error>> {
+fn apply_benes(r: &mut [u8; (1 << GFBITS) / 8], bits: &[u8; COND_BYTES], rev: usize) {
+    if GFBITS == 12 {
+        apply_benes_12(<&mut [u8; 512]>::try_from(&mut r[..]).unwrap(), bits, rev)
+    } else {
+        apply_benes_13(<&mut [u8; 1024]>::try_from(&mut r[..]).unwrap(), bits, rev)
+    }
+}
+
+/// Beneš network for GF(2^12) where `r` has 512 bytes and `bits` has 5888 bytes.
+fn apply_benes_12(r: &mut [u8; 512], bits: &[u8], rev: usize) {
     let mut bs = [0u64; 64];
     let mut cond = [0u64; 64];
 
@@ -250,19 +253,11 @@ fn apply_benes(
         }
     }
 
-    Ok(())
+    zeroize!(bs, cond);
 }
 
-/// Apply Beneš network in-place to array `r` based on configuration `bits` and `rev`.
-/// Here, `r` is a sequence of bits to be permuted.
-/// `bits` defines the condition bits configuring the Beneš network and
-/// `rev` toggles between normal application (0) or its inverse (!0).
-#[cfg(not(any(feature = "mceliece348864", feature = "mceliece348864f")))]
-fn apply_benes(
-    r: &mut [u8; 1024],
-    bits: &[u8; COND_BYTES],
-    rev: usize,
-) -> Result<(), Box<dyn error::Error>> {
+/// Beneš network for GF(2^13) where `r` has 1024 bytes and `bits` has 12800 bytes.
+fn apply_benes_13(r: &mut [u8; 1024], bits: &[u8], rev: usize) {
     let mut r_int_v = [[0u64; 64]; 2];
     let mut r_int_h = [[0u64; 64]; 2];
     let mut b_int_v = [0u64; 64];
@@ -291,7 +286,7 @@ fn apply_benes(
 
         transpose::transpose(&mut b_int_h, b_int_v);
 
-        layer_ex(&mut r_int_h, &mut b_int_h, iter);
+        layer_ex(&mut r_int_h, &b_int_h, iter);
     }
 
     transpose::transpose(&mut r_int_v[0], r_int_h[0]);
@@ -308,7 +303,7 @@ fn apply_benes(
             calc_index - 512
         };
 
-        layer_in(&mut r_int_v, &mut b_int_v, iter);
+        layer_in(&mut r_int_v, &b_int_v, iter);
     }
 
     for iter in (0..=4).rev() {
@@ -321,7 +316,7 @@ fn apply_benes(
             calc_index - 512
         };
 
-        layer_in(&mut r_int_v, &mut b_int_v, iter);
+        layer_in(&mut r_int_v, &b_int_v, iter);
     }
 
     transpose::transpose(&mut r_int_h[0], r_int_v[0]);
@@ -341,7 +336,7 @@ fn apply_benes(
 
         transpose::transpose(&mut b_int_h, b_int_v);
 
-        layer_ex(&mut r_int_h, &mut b_int_h, iter);
+        layer_ex(&mut r_int_h, &b_int_h, iter);
     }
 
     transpose::transpose(&mut r_int_v[0], r_int_h[0]);
@@ -352,13 +347,11 @@ fn apply_benes(
         util::store8(sub!(mut chunk, 8, 8), r_int_v[1][i]);
     }
 
-    Ok(())
+    zeroize!(r_int_v, r_int_h, b_int_v, b_int_h);
 }
 
-pub(crate) fn support_gen(
-    s: &mut [Gf; SYS_N],
-    c: &[u8; COND_BYTES],
-) -> Result<(), Box<dyn error::Error>> {
+#[allow(clippy::needless_range_loop)]
+pub(crate) fn support_gen(s: &mut [Gf; SYS_N], c: &[u8; COND_BYTES]) {
     let mut a: Gf;
     let mut l = [[0u8; (1 << GFBITS) / 8]; GFBITS];
 
@@ -371,14 +364,7 @@ pub(crate) fn support_gen(
     }
 
     for j in 0..GFBITS {
-        #[cfg(any(feature = "mceliece348864", feature = "mceliece348864f"))]
-        {
-            apply_benes(&mut l[j], c, 0)?;
-        }
-        #[cfg(not(any(feature = "mceliece348864", feature = "mceliece348864f")))]
-        {
-            apply_benes(&mut l[j], c, 0)?;
-        }
+        apply_benes(&mut l[j], c, 0);
     }
 
     for i in 0..SYS_N {
@@ -389,17 +375,19 @@ pub(crate) fn support_gen(
         }
     }
 
-    Ok(())
+    zeroize!(l);
 }
 
 #[cfg(test)]
 mod tests {
+    use super::super::CRYPTO_PRIMITIVE;
     use super::*;
-    use crate::api::CRYPTO_PRIMITIVE;
     use std::convert::TryFrom;
 
     fn get(name: &str) -> Vec<u64> {
-        let fullname = format!("{}_{}", CRYPTO_PRIMITIVE, name);
+        // the test data is only stored for the base variants
+        let variant = crate::TestData::base_variant(CRYPTO_PRIMITIVE);
+        let fullname = format!("{}_{}", variant, name);
         crate::TestData::new().u64vec(&fullname)
     }
 
@@ -407,8 +395,8 @@ mod tests {
         <[u64; 64]>::try_from(get(name).as_slice()).unwrap()
     }
 
-    #[cfg(any(feature = "mceliece348864", feature = "mceliece348864f"))]
     #[test]
+    #[allow(clippy::needless_range_loop)]
     fn test_layer() {
         let mut data = [0u64; 64];
         let mut bits = [0u64; 32];
@@ -433,38 +421,49 @@ mod tests {
         );
     }
 
-    #[cfg(any(feature = "mceliece348864", feature = "mceliece348864f"))]
-    #[test]
-    fn test_layer_2() {
-        let mut data_arg = get64("benes_layer_data_before");
-        let bits_arg = get("benes_layer_bits");
-        layer(&mut data_arg, &bits_arg, 0);
-        let actual_data = data_arg;
+    variant_tests! {
+        [mceliece348864, mceliece348864f, mceliece348864pc, mceliece348864pcf]
+
+        #[test]
+        fn test_layer_2() {
+            let mut data_arg = get64("benes_layer_data_before");
+            let bits_arg = get("benes_layer_bits");
+            layer(&mut data_arg, &bits_arg, 0);
+            let actual_data = data_arg;
 
-        let expected_data = get64("benes_layer_data_after");
+            let expected_data = get64("benes_layer_data_after");
 
-        assert_eq!(actual_data, expected_data);
+            assert_eq!(actual_data, expected_data);
+        }
     }
 
-    #[cfg(not(any(feature = "mceliece348864", feature = "mceliece348864f")))]
-    #[test]
-    fn test_layer_in() {
-        let data0_arg = get64("benes_layer_in_data0_before");
-        let data1_arg = get64("benes_layer_in_data1_before");
-        let mut data_arg = [data0_arg, data1_arg];
-        let bits_arg = get64("benes_layer_in_bits");
-        layer_in(&mut data_arg, &bits_arg, 0);
-        let actual_data = data_arg;
-
-        let expected_data0 = get64("benes_layer_in_data0_after");
-        let expected_data1 = get64("benes_layer_in_data1_after");
-        let expected_data = [expected_data0, expected_data1];
-
-        assert_eq!(actual_data, expected_data);
+    variant_tests! {
+        [
+            mceliece460896, mceliece460896f, mceliece460896pc, mceliece460896pcf, mceliece6688128,
+            mceliece6688128f, mceliece6688128pc, mceliece6688128pcf, mceliece6960119,
+            mceliece6960119f, mceliece6960119pc, mceliece6960119pcf, mceliece8192128,
+            mceliece8192128f, mceliece8192128pc, mceliece8192128pcf,
+        ]
+
+        #[test]
+        fn test_layer_in() {
+            let data0_arg = get64("benes_layer_in_data0_before");
+            let data1_arg = get64("benes_layer_in_data1_before");
+            let mut data_arg = [data0_arg, data1_arg];
+            let bits_arg = get64("benes_layer_in_bits");
+            layer_in(&mut data_arg, &bits_arg, 0);
+            let actual_data = data_arg;
+
+            let expected_data0 = get64("benes_layer_in_data0_after");
+            let expected_data1 = get64("benes_layer_in_data1_after");
+            let expected_data = [expected_data0, expected_data1];
+
+            assert_eq!(actual_data, expected_data);
+        }
     }
 
-    #[cfg(not(any(feature = "mceliece348864", feature = "mceliece348864f")))]
     #[test]
+    #[allow(clippy::needless_range_loop)]
     fn test_layer_ex() {
         let mut data = [[0u64; 64]; 2];
         let mut bits = [0u64; 64];
@@ -503,38 +502,49 @@ mod tests {
         );
     }
 
-    #[cfg(any(feature = "mceliece348864", feature = "mceliece348864f"))]
-    #[test]
-    fn test_apply_benes() -> Result<(), Box<dyn error::Error>> {
-        let t = crate::TestData::new();
-        let mut r_arg =
-            <[u8; 512]>::try_from(t.u8vec("mceliece348864_benes_apply_benes_r_before")).unwrap();
-        let bits_arg =
-            <[u8; 5888]>::try_from(t.u8vec("mceliece348864_benes_apply_benes_bits")).unwrap();
-        apply_benes(&mut r_arg, &bits_arg, 0)?;
-        let actual_r = r_arg;
-        let expected_r =
-            <[u8; 512]>::try_from(t.u8vec("mceliece348864_benes_apply_benes_r_after")).unwrap();
-        assert_eq!(actual_r, expected_r);
-        Ok(())
+    variant_tests! {
+        [mceliece348864, mceliece348864f, mceliece348864pc, mceliece348864pcf]
+
+        #[test]
+        fn test_apply_benes_12() {
+            let t = crate::TestData::new();
+            let mut r_arg =
+                <[u8; 512]>::try_from(t.u8vec("mceliece348864_benes_apply_benes_r_before"))
+                    .unwrap();
+            let bits_arg =
+                <[u8; 5888]>::try_from(t.u8vec("mceliece348864_benes_apply_benes_bits")).unwrap();
+            apply_benes_12(&mut r_arg, &bits_arg, 0);
+            let actual_r = r_arg;
+            let expected_r =
+                <[u8; 512]>::try_from(t.u8vec("mceliece348864_benes_apply_benes_r_after")).unwrap();
+            assert_eq!(actual_r, expected_r);
+        }
     }
 
-    #[cfg(not(any(feature = "mceliece348864", feature = "mceliece348864f")))]
-    #[test]
-    fn test_apply_benes() -> Result<(), Box<dyn error::Error>> {
-        let t = crate::TestData::new();
-        let mut r_arg =
-            <[u8; 1024]>::try_from(t.u8vec("mceliece460896orlarger_benes_apply_benes_r_before"))
-                .unwrap();
-        let bits_arg =
-            <[u8; COND_BYTES]>::try_from(t.u8vec("mceliece460896orlarger_benes_apply_benes_bits"))
-                .unwrap(); // TODO actual array has wrong size of 12_800
-        apply_benes(&mut r_arg, &bits_arg, 0)?;
-        let actual_r = r_arg;
-        let expected_r =
-            <[u8; 1024]>::try_from(t.u8vec("mceliece460896orlarger_benes_apply_benes_r_after"))
-                .unwrap();
-        assert_eq!(actual_r, expected_r);
-        Ok(())
+    variant_tests! {
+        [
+            mceliece460896, mceliece460896f, mceliece460896pc, mceliece460896pcf, mceliece6688128,
+            mceliece6688128f, mceliece6688128pc, mceliece6688128pcf, mceliece6960119,
+            mceliece6960119f, mceliece6960119pc, mceliece6960119pcf, mceliece8192128,
+            mceliece8192128f, mceliece8192128pc, mceliece8192128pcf,
+        ]
+
+        #[test]
+        fn test_apply_benes_13() {
+            let t = crate::TestData::new();
+            let mut r_arg = <[u8; 1024]>::try_from(
+                t.u8vec("mceliece460896orlarger_benes_apply_benes_r_before"),
+            )
+            .unwrap();
+            let bits_arg =
+                <[u8; 12800]>::try_from(t.u8vec("mceliece460896orlarger_benes_apply_benes_bits"))
+                    .unwrap(); // TODO actual array has wrong size of 12_800
+            apply_benes_13(&mut r_arg, &bits_arg, 0);
+            let actual_r = r_arg;
+            let expected_r =
+                <[u8; 1024]>::try_from(t.u8vec("mceliece460896orlarger_benes_apply_benes_r_after"))
+                    .unwrap();
+            assert_eq!(actual_r, expected_r);
+        }
     }
 }
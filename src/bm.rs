@@ -1,8 +1,9 @@
 //! This file implements the Berlekamp-Massey algorithm
 //! see <http://crypto.stanford.edu/~mironov/cs359/massey.pdf>
 
-use crate::gf::{gf_frac, gf_mul, Gf};
-use crate::params::SYS_T;
+use super::gf::{gf_frac, gf_mul, Gf};
+use super::params::SYS_T;
+use crate::macros::zeroize;
 
 fn min(a: usize, b: usize) -> usize {
     let c = (a < b) as isize;
@@ -44,9 +45,7 @@ pub(crate) fn bm(out: &mut [Gf; SYS_T + 1], s: &mut [Gf; 2 * SYS_T]) {
         mle = mle.wrapping_sub(1);
         mle &= mne;
 
-        for i in 0..=SYS_T {
-            t[i] = c[i];
-        }
+        t.copy_from_slice(&c);
 
         let f: Gf = gf_frac(base, d);
 
@@ -72,57 +71,61 @@ pub(crate) fn bm(out: &mut [Gf; SYS_T + 1], s: &mut [Gf; 2 * SYS_T]) {
     for i in 0..=SYS_T {
         out[i] = c[SYS_T - i];
     }
+
+    zeroize!(t, c, b);
 }
 
 #[cfg(test)]
-#[cfg(feature = "mceliece8192128f")]
-mod tests {
-    use super::*;
-    use crate::macros::sub;
-    use std::error;
-
-    #[test]
-    fn test_simple_bm() {
-        assert_eq!(SYS_T + 1, 129);
-
-        let compare_array: [u16; 129] = [
-            7438, 1794, 2310, 1794, 5390, 1794, 2310, 1794, 3333, 1794, 2310, 1794, 5390, 1794,
-            2310, 1794, 7432, 1794, 2310, 1794, 5390, 1794, 2310, 1794, 3333, 1794, 2310, 1794,
-            5390, 1794, 2310, 1794, 7433, 1794, 2310, 1794, 5390, 1794, 2310, 1794, 3333, 1794,
-            2310, 1794, 5390, 1794, 2310, 1794, 7432, 1794, 2310, 1794, 5390, 1794, 2310, 1794,
-            3333, 1794, 2310, 1794, 5390, 1794, 2310, 1794, 7435, 1794, 2310, 1794, 5390, 1794,
-            2310, 1794, 3333, 1794, 2310, 1794, 5390, 1794, 2310, 1794, 7432, 1794, 2310, 1794,
-            5390, 1794, 2310, 1794, 3333, 1794, 2310, 1794, 5390, 1794, 2310, 1794, 7433, 1794,
-            2310, 1794, 5390, 1794, 2310, 1794, 3333, 1794, 2310, 1794, 5390, 1794, 2310, 1794,
-            7432, 1794, 2310, 1794, 5390, 1794, 2310, 1794, 3333, 1794, 2310, 1794, 5390, 1794,
-            2310, 1794, 1,
-        ];
-
-        let mut locator = [0u16; SYS_T + 1];
-        let mut s = [0u16; SYS_T * 2];
-
-        for i in 0..s.len() {
-            s[i] = i as u16;
+variant_tests! {
+    [mceliece8192128f]
+
+    mod tests {
+        use super::*;
+        use crate::macros::sub;
+
+        #[test]
+        #[allow(clippy::needless_range_loop)]
+        fn test_simple_bm() {
+            assert_eq!(SYS_T + 1, 129);
+
+            let compare_array: [u16; 129] = [
+                7438, 1794, 2310, 1794, 5390, 1794, 2310, 1794, 3333, 1794, 2310, 1794, 5390, 1794,
+                2310, 1794, 7432, 1794, 2310, 1794, 5390, 1794, 2310, 1794, 3333, 1794, 2310, 1794,
+                5390, 1794, 2310, 1794, 7433, 1794, 2310, 1794, 5390, 1794, 2310, 1794, 3333, 1794,
+                2310, 1794, 5390, 1794, 2310, 1794, 7432, 1794, 2310, 1794, 5390, 1794, 2310, 1794,
+                3333, 1794, 2310, 1794, 5390, 1794, 2310, 1794, 7435, 1794, 2310, 1794, 5390, 1794,
+                2310, 1794, 3333, 1794, 2310, 1794, 5390, 1794, 2310, 1794, 7432, 1794, 2310, 1794,
+                5390, 1794, 2310, 1794, 3333, 1794, 2310, 1794, 5390, 1794, 2310, 1794, 7433, 1794,
+                2310, 1794, 5390, 1794, 2310, 1794, 3333, 1794, 2310, 1794, 5390, 1794, 2310, 1794,
+                7432, 1794, 2310, 1794, 5390, 1794, 2310, 1794, 3333, 1794, 2310, 1794, 5390, 1794,
+                2310, 1794, 1,
+            ];
+
+            let mut locator = [0u16; SYS_T + 1];
+            let mut s = [0u16; SYS_T * 2];
+
+            for i in 0..s.len() {
+                s[i] = i as u16;
+            }
+
+            bm(&mut locator, &mut s);
+
+            assert_eq!(locator[..], compare_array[..]);
         }
 
-        bm(&mut locator, &mut s);
-
-        assert_eq!(locator, compare_array);
-    }
-
-    #[test]
-    fn test_first_round_bm() -> Result<(), Box<dyn error::Error>> {
-        let compare_array =
-            crate::TestData::new().u16vec("mceliece8192128f_bm_first_round_compare_array");
-        let compare_array_slice = sub!(compare_array.as_slice(), 0, SYS_T + 1, u16);
-        let mut s_input = crate::TestData::new().u16vec("mceliece8192128f_bm_first_round_s_input");
-        let s_input_slice = sub!(mut s_input.as_mut_slice(), 0, 2 * SYS_T, u16);
+        #[test]
+        fn test_first_round_bm() {
+            let compare_array =
+                crate::TestData::new().u16vec("mceliece8192128f_bm_first_round_compare_array");
+            let compare_array_slice = sub!(compare_array.as_slice(), 0, SYS_T + 1, u16);
+            let mut s_input =
+                crate::TestData::new().u16vec("mceliece8192128f_bm_first_round_s_input");
+            let s_input_slice = sub!(mut s_input.as_mut_slice(), 0, 2 * SYS_T, u16);
 
-        let mut locator = [0u16; SYS_T + 1];
-        bm(&mut locator, s_input_slice);
+            let mut locator = [0u16; SYS_T + 1];
+            bm(&mut locator, s_input_slice);
 
-        assert_eq!(&locator, compare_array_slice);
-
-        Ok(())
+            assert_eq!(&locator, compare_array_slice);
+        }
     }
 }
@@ -4,8 +4,9 @@
 //! [^cb]: Daniel J. Bernstein "Verified fast formulas for control bits for permutation networks"
 //!        <https://cr.yp.to/papers/controlbits-20200923.pdf>
 
-use crate::int32_sort::int32_sort;
-use crate::params::GFBITS;
+use super::params::GFBITS;
+use super::util::{large_array, LargeArray};
+use crate::{int32_sort::int32_sort, macros::zeroize};
 
 // controlbits returns ((2*m - 1) * 2^(m - 1)) control bits for a permutation pi over 2^m indices
 // equals ((2*m - 1) * 2^(m - 1))/8 control bytes, which is what this macro computes.
@@ -34,12 +35,12 @@ fn layer(p: &mut [i16], cb: &[u8], s: i32, n: i32) {
 
     for i in (0..n as usize).step_by(stride * 2) {
         for j in 0..stride {
-            let mut d = (p[(i + j) as usize] ^ p[(i + j + stride) as usize]) as i16;
+            let mut d = p[i + j] ^ p[i + j + stride];
             let mut m = ((cb[index >> 3] >> (index & 7)) & 1) as i16;
             m = -m;
             d &= m;
-            p[(i + j) as usize] ^= d as i16;
-            p[(i + j + stride) as usize] ^= d as i16;
+            p[i + j] ^= d;
+            p[i + j + stride] ^= d;
             index += 1;
         }
     }
@@ -63,6 +64,7 @@ fn layer(p: &mut [i16], cb: &[u8], s: i32, n: i32) {
 ///
 /// But the following descriptions still hold true:
 ///   out is filled with (2m-1)n/2 control bits at positions pos, pos+step, …
+#[allow(clippy::needless_range_loop, clippy::too_many_arguments)]
 fn cbrecursion(
     out: &mut [u8],
     mut pos: usize,
@@ -284,12 +286,13 @@ fn cbrecursion(
 /// The control bits provide the configuration for a Beneš network in order
 /// to implement the permutation specified by `pi`. The first control bit is
 /// the LSB of out[0].
+#[allow(clippy::needless_range_loop)]
 pub(crate) fn controlbitsfrompermutation(out: &mut [u8], pi: &[i16], w: usize, n: usize) {
     assert_eq!(n, 1 << w);
     assert_eq!(pi.len(), n);
-    assert_eq!(out.len(), (((2 * w - 1) * n / 2) + 7) / 8);
+    assert_eq!(out.len(), ((2 * w - 1) * n / 2).div_ceil(8));
 
-    let mut temp = [0i32; 2 * (1 << GFBITS)];
+    let mut temp: LargeArray<i32, { 2 * (1 << GFBITS) }> = large_array(0i32);
     let mut diff: i16 = 0;
 
     // reinterpret pi as i32 array
@@ -300,27 +303,27 @@ pub(crate) fn controlbitsfrompermutation(out: &mut [u8], pi: &[i16], w: usize, n
     }
 
     let mut sub = out;
+    let mut pi_test = [0i16; 1 << GFBITS];
 
     loop {
         sub.fill(0);
-        cbrecursion(sub, 0, 1, 0, w, n, &mut temp, &pi_as_i32);
+        cbrecursion(sub, 0, 1, 0, w, n, &mut temp[..], &pi_as_i32);
 
-        let mut pi_test = [0i16; 1 << GFBITS];
         for i in 0..n {
             pi_test[i] = i as i16;
         }
 
-        for i in 0..w as usize {
+        for i in 0..w {
             layer(&mut pi_test, sub, i as i32, n as i32);
-            sub = &mut sub[(n >> 4) as usize..];
+            sub = &mut sub[(n >> 4)..];
         }
 
         for i in (0..w - 1).rev() {
             layer(&mut pi_test, sub, i as i32, n as i32);
-            sub = &mut sub[(n >> 4) as usize..];
+            sub = &mut sub[(n >> 4)..];
         }
 
-        for i in 0..n as usize {
+        for i in 0..n {
             diff |= pi[i] ^ pi_test[i];
         }
 
@@ -328,6 +331,8 @@ pub(crate) fn controlbitsfrompermutation(out: &mut [u8], pi: &[i16], w: usize, n
             break;
         }
     }
+
+    zeroize!(temp, pi_as_i32, pi_test);
 }
 
 #[cfg(test)]
@@ -418,61 +423,65 @@ mod tests {
         assert_eq!(out.len(), out_ref.len());
     }
 
-    // This testcase corresponds to the call of controlbitsfrompermutation
-    // in the 3rd KAT testcase of the mceliece348864 reference implementation
-    #[test]
-    #[cfg(feature = "mceliece348864")]
-    fn test_controlbitsfrompermutation_kat3_mceliece348864() {
-        let pi = crate::TestData::new().i16vec("controlbits_kat3_mceliece348864_pi");
-        let mut out = [0u8; 5888];
+    variant_tests! {
+        [mceliece348864]
 
-        controlbitsfrompermutation(&mut out, &pi, 12, 4096);
+        // This testcase corresponds to the call of controlbitsfrompermutation
+        // in the 3rd KAT testcase of the mceliece348864 reference implementation
+        #[test]
+        fn test_controlbitsfrompermutation_kat3_mceliece348864() {
+            let pi = crate::TestData::new().i16vec("controlbits_kat3_mceliece348864_pi");
+            let mut out = [0u8; 5888];
 
-        let out_ref = crate::TestData::new().u8vec("controlbits_kat3_mceliece348864_out_ref");
-        assert_eq!(&out, out_ref.as_slice());
-    }
+            controlbitsfrompermutation(&mut out, &pi, 12, 4096);
 
-    // This testcase corresponds to the call of controlbitsfrompermutation
-    // in the 8th KAT testcase of the mceliece348864 reference implementation
-    #[test]
-    #[cfg(feature = "mceliece348864")]
-    fn test_controlbitsfrompermutation_kat8_mceliece348864() {
-        let mut out = [0u8; 5888];
-        let pi = crate::TestData::new().i16vec("controlbits_kat8_mceliece348864_pi");
+            let out_ref = crate::TestData::new().u8vec("controlbits_kat3_mceliece348864_out_ref");
+            assert_eq!(&out, out_ref.as_slice());
+        }
 
-        controlbitsfrompermutation(&mut out, &pi, 12, 4096);
+        // This testcase corresponds to the call of controlbitsfrompermutation
+        // in the 8th KAT testcase of the mceliece348864 reference implementation
+        #[test]
+        fn test_controlbitsfrompermutation_kat8_mceliece348864() {
+            let mut out = [0u8; 5888];
+            let pi = crate::TestData::new().i16vec("controlbits_kat8_mceliece348864_pi");
 
-        let out_ref = crate::TestData::new().u8vec("controlbits_kat8_mceliece348864_out_ref");
-        assert_eq!(out, out_ref.as_slice());
-    }
+            controlbitsfrompermutation(&mut out, &pi, 12, 4096);
 
-    // This testcase corresponds to the call of controlbitsfrompermutation
-    // in the 9th KAT testcase of the mceliece348864 reference implementation
-    #[test]
-    #[cfg(feature = "mceliece348864")]
-    fn test_controlbitsfrompermutation_kat9_mceliece348864() {
-        let mut out = [0u8; 5888];
-        let pi = crate::TestData::new().i16vec("controlbits_kat9_mceliece348864_pi");
+            let out_ref = crate::TestData::new().u8vec("controlbits_kat8_mceliece348864_out_ref");
+            assert_eq!(out, out_ref.as_slice());
+        }
 
-        controlbitsfrompermutation(&mut out, &pi, 12, 4096);
+        // This testcase corresponds to the call of controlbitsfrompermutation
+        // in the 9th KAT testcase of the mceliece348864 reference implementation
+        #[test]
+        fn test_controlbitsfrompermutation_kat9_mceliece348864() {
+            let mut out = [0u8; 5888];
+            let pi = crate::TestData::new().i16vec("controlbits_kat9_mceliece348864_pi");
 
-        let out_ref = crate::TestData::new().u8vec("controlbits_kat9_mceliece348864_out_ref");
-        assert_eq!(out, out_ref.as_slice());
+            controlbitsfrompermutation(&mut out, &pi, 12, 4096);
+
+            let out_ref = crate::TestData::new().u8vec("controlbits_kat9_mceliece348864_out_ref");
+            assert_eq!(out, out_ref.as_slice());
+        }
     }
 
-    // This testcase corresponds to the call of controlbitsfrompermutation
-    // in the 3rd KAT testcase of the mceliece6960119 reference implementation
-    #[test]
-    #[cfg(feature = "mceliece6960119")]
-    fn test_controlbitsfrompermutation_kat3_mceliece6960119() {
-        assert_eq!(GFBITS, 13);
+    variant_tests! {
+        [mceliece6960119]
+
+        // This testcase corresponds to the call of controlbitsfrompermutation
+        // in the 3rd KAT testcase of the mceliece6960119 reference implementation
+        #[test]
+        fn test_controlbitsfrompermutation_kat3_mceliece6960119() {
+            assert_eq!(GFBITS, 13);
 
-        let mut out = [0u8; 12800];
+            let mut out = [0u8; 12800];
 
-        let pi = crate::TestData::new().i16vec("controlbits_kat3_mceliece6960119_pi");
-        controlbitsfrompermutation(&mut out, &pi, 13, 8192);
+            let pi = crate::TestData::new().i16vec("controlbits_kat3_mceliece6960119_pi");
+            controlbitsfrompermutation(&mut out, &pi, 13, 8192);
 
-        let out_ref = crate::TestData::new().u8vec("controlbits_kat3_mceliece6960119_out_ref");
-        assert_eq!(out, out_ref.as_slice());
+            let out_ref = crate::TestData::new().u8vec("controlbits_kat3_mceliece6960119_out_ref");
+            assert_eq!(out, out_ref.as_slice());
+        }
     }
 }
@@ -1,51 +1,114 @@
 //! Hash function implementations (only SHAKE)
 
-use std::error;
-use std::fmt;
-use std::io::Read;
+use rand_core::{impls, CryptoRng, RngCore};
+use sha3::digest::{ExtendableOutput, Update, XofReader};
+use sha3::{Sha3XofReader, Shake256};
 
-use sha3::digest::{ExtendableOutput, Update};
-use sha3::Shake256;
+/// Utilizes the SHAKE256 hash function. Input and output is of arbitrary length.
+pub(crate) fn shake256(output: &mut [u8], input: &[u8]) {
+    let mut shake_hash_fn = Shake256::default();
+    shake_hash_fn.update(input);
 
-#[derive(Debug)]
-struct ShakeIOError(String);
+    let mut result_shake = shake_hash_fn.finalize_xof();
+    result_shake.read(output);
+}
 
-impl error::Error for ShakeIOError {}
+/// Incremental SHAKE256 for inputs which are not available as one contiguous slice
+pub(crate) struct Shake256Stream(Shake256);
+
+impl Shake256Stream {
+    pub(crate) fn new() -> Self {
+        Shake256Stream(Shake256::default())
+    }
+
+    /// Absorb `input`
+    pub(crate) fn update(&mut self, input: &[u8]) {
+        self.0.update(input);
+    }
 
-impl fmt::Display for ShakeIOError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "shake failed to read data: {}", self.0)
+    /// Squeeze `output.len()` bytes
+    pub(crate) fn finalize(self, output: &mut [u8]) {
+        self.0.finalize_xof().read(output);
     }
 }
 
-/// Utilizes the SHAKE256 hash function. Input and output is of arbitrary length.
-pub(crate) fn shake256(output: &mut [u8], input: &[u8]) -> Result<(), Box<dyn error::Error>> {
-    let mut shake_hash_fn = Shake256::default();
-    shake_hash_fn.update(input);
+/// Deterministic random number generator squeezing SHAKE256 of its seed.
+/// Used to expand the coins of derandomized encapsulation.
+pub(crate) struct Shake256Rng(Sha3XofReader);
 
-    let mut result_shake = shake_hash_fn.finalize_xof();
-    match result_shake.read(output) {
-        Ok(_) => Ok(()),
-        Err(e) => Err(Box::new(ShakeIOError(e.to_string()))),
+impl Shake256Rng {
+    /// Absorb `prefix` followed by `seed`
+    pub(crate) fn new(prefix: &[u8], seed: &[u8]) -> Self {
+        let mut shake_hash_fn = Shake256::default();
+        shake_hash_fn.update(prefix);
+        shake_hash_fn.update(seed);
+        Shake256Rng(shake_hash_fn.finalize_xof())
+    }
+}
+
+impl RngCore for Shake256Rng {
+    fn next_u32(&mut self) -> u32 {
+        impls::next_u32_via_fill(self)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        impls::next_u64_via_fill(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0.read(dest);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
     }
 }
 
+impl CryptoRng for Shake256Rng {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::error;
 
     #[test]
-    fn test_shake256() -> Result<(), Box<dyn error::Error>> {
+    fn test_shake256() {
         let compare_array = crate::TestData::new().u8vec("shake256_digest_expected");
 
         let mut c = [0u8; 448];
         let mut two_e = [0u8; 1025];
         two_e[0] = 2;
 
-        shake256(&mut c[208..=239], &two_e[0..1025])?;
+        shake256(&mut c[208..=239], &two_e[0..1025]);
         assert_eq!(&c, compare_array.as_slice());
+    }
 
-        Ok(())
+    #[test]
+    fn test_shake256_stream() {
+        let input = [0x5Au8; 300];
+        let mut expected = [0u8; 64];
+        shake256(&mut expected, &input);
+
+        let mut stream = Shake256Stream::new();
+        stream.update(&input[..7]);
+        stream.update(&input[7..200]);
+        stream.update(&input[200..]);
+        let mut output = [0u8; 64];
+        stream.finalize(&mut output);
+
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_shake256_rng() {
+        let mut expected = [0u8; 100];
+        shake256(&mut expected, b"prefix|seed");
+
+        let mut rng = Shake256Rng::new(b"prefix|", b"seed");
+        let mut output = [0u8; 100];
+        rng.fill_bytes(&mut output[..30]);
+        rng.try_fill_bytes(&mut output[30..]).unwrap();
+
+        assert_eq!(output, expected);
     }
 }
@@ -1,14 +1,15 @@
 //! Encryption function to compute error vector and syndrome to get ciphertext
 
-use std::error;
-
-use crate::{
-    api::CRYPTO_CIPHERTEXTBYTES,
-    macros::sub,
-    params::{PK_NROWS, PK_ROW_BYTES, SYND_BYTES, SYS_N, SYS_T},
-    randombytes::RNGState,
+use super::{
+    params::{GFBITS, PK_NROWS, PK_ROW_BYTES, SYND_BYTES, SYS_N, SYS_T},
     util::load_gf,
+    CRYPTO_CIPHERTEXTBYTES,
+};
+use crate::{
+    error::Error,
+    macros::{sub, zeroize},
 };
+use rand_core::RngCore;
 
 /// Takes two 16-bit integers and determines whether they are equal (u8::MAX) or different (0)
 fn same_mask_u8(x: u16, y: u16) -> u8 {
@@ -23,16 +24,30 @@ fn same_mask_u8(x: u16, y: u16) -> u8 {
 /// Generation of `e`, an error vector of weight `t`.
 /// Does not take any input arguments.
 /// If generation of pseudo-random numbers fails, an error is returned.
-#[cfg(not(any(feature = "mceliece8192128", feature = "mceliece8192128f")))]
-fn gen_e(e: &mut [u8; SYS_N / 8], rng: &mut impl RNGState) -> Result<(), Box<dyn error::Error>> {
+pub(crate) fn gen_e(e: &mut [u8; SYS_N / 8], rng: &mut impl RngCore) -> Result<(), Error> {
+    // if every GFBITS-bit integer is a valid index, no candidates need to be discarded
+    if SYS_N == 1 << GFBITS {
+        gen_e_full_length(e, rng)
+    } else {
+        gen_e_shortened(e, rng)
+    }
+}
+
+/// Generation of `e` for codes of length `SYS_N < 2^GFBITS`.
+/// Out-of-range indices are discarded, hence twice as many candidates are sampled.
+#[allow(clippy::needless_range_loop)]
+fn gen_e_shortened(e: &mut [u8; SYS_N / 8], rng: &mut impl RngCore) -> Result<(), Error> {
     let mut ind = [0u16; SYS_T];
     let mut val = [0u8; SYS_T];
+    let mut bytes = [0u8; SYS_T * 4];
+    let mut nums = [0u16; SYS_T * 2];
 
     loop {
-        let mut bytes = [0u8; SYS_T * 4];
-        rng.randombytes(&mut bytes)?;
+        if let Err(err) = rng.try_fill_bytes(&mut bytes) {
+            zeroize!(ind, val, bytes, nums);
+            return Err(err.into());
+        }
 
-        let mut nums = [0u16; SYS_T * 2];
         for (i, chunk) in bytes.chunks(2).enumerate() {
             nums[i] = load_gf(sub!(chunk, 0, 2));
         }
@@ -85,20 +100,23 @@ fn gen_e(e: &mut [u8; SYS_N / 8], rng: &mut impl RNGState) -> Result<(), Box<dyn
         }
     }
 
+    zeroize!(ind, val, bytes, nums);
+
     Ok(())
 }
 
-/// Generation of `e`, an error vector of weight `t`.
-/// Does not take any input arguments.
-/// If generation of pseudo-random numbers fails, an error is returned.
-#[cfg(any(feature = "mceliece8192128", feature = "mceliece8192128f"))]
-fn gen_e(e: &mut [u8], rng: &mut impl RNGState) -> Result<(), Box<dyn error::Error>> {
+/// Generation of `e` for codes of length `SYS_N = 2^GFBITS`.
+#[allow(clippy::needless_range_loop)]
+fn gen_e_full_length(e: &mut [u8; SYS_N / 8], rng: &mut impl RngCore) -> Result<(), Error> {
     let mut ind = [0u16; SYS_T];
     let mut bytes = [0u8; SYS_T * 2];
     let mut val = [0u8; SYS_T];
 
     loop {
-        rng.randombytes(&mut bytes)?;
+        if let Err(err) = rng.try_fill_bytes(&mut bytes) {
+            zeroize!(ind, bytes, val);
+            return Err(err.into());
+        }
 
         for (i, chunk) in bytes.chunks(2).enumerate() {
             ind[i] = load_gf(sub!(chunk, 0, 2));
@@ -135,153 +153,119 @@ fn gen_e(e: &mut [u8], rng: &mut impl RNGState) -> Result<(), Box<dyn error::Err
         }
     }
 
+    zeroize!(ind, bytes, val);
+
     Ok(())
 }
 
 /// Syndrome computation.
 ///
 /// Computes syndrome `s` based on public key `pk` and error vector `e`.
-#[cfg(any(feature = "mceliece6960119", feature = "mceliece6960119f"))]
-fn syndrome(
-    s: &mut [u8; (PK_NROWS + 7) / 8],
+pub(crate) fn syndrome(
+    s: &mut [u8; PK_NROWS.div_ceil(8)],
     pk: &[u8; PK_NROWS * PK_ROW_BYTES],
     e: &[u8; SYS_N / 8],
 ) {
-    let mut row = [0u8; SYS_N / 8];
+    s[0..SYND_BYTES].fill(0);
 
-    let mut pk_segment = &pk[..];
-    let tail = PK_NROWS % 8;
+    for (i, pk_row) in pk.chunks(PK_ROW_BYTES).enumerate() {
+        syndrome_row(s, i, sub!(pk_row, 0, PK_ROW_BYTES), e);
+    }
+}
 
-    s[0..SYND_BYTES].fill(0);
+/// Adds the syndrome bit of row `i` of the public key, given as `pk_row`, to `s`.
+/// `s` must be zeroed before the first row. Rows can be processed as soon as they are available,
+/// hence the public key does not need to be stored as a whole.
+#[allow(clippy::manual_is_multiple_of)]
+pub(crate) fn syndrome_row(
+    s: &mut [u8; PK_NROWS.div_ceil(8)],
+    i: usize,
+    pk_row: &[u8; PK_ROW_BYTES],
+    e: &[u8; SYS_N / 8],
+) {
+    let mut row = [0u8; SYS_N / 8];
 
-    for i in 0..PK_NROWS {
-        row[0..SYS_N / 8].fill(0);
+    row[SYS_N / 8 - PK_ROW_BYTES..].copy_from_slice(pk_row);
 
-        for j in 0..PK_ROW_BYTES {
-            row[SYS_N / 8 - PK_ROW_BYTES + j] = pk_segment[j];
-        }
+    // rows do not start at a byte boundary of the codeword if `PK_NROWS % 8 != 0`
+    if PK_NROWS % 8 != 0 {
+        let tail = PK_NROWS % 8;
 
         for j in ((SYS_N / 8 - PK_ROW_BYTES)..SYS_N / 8).rev() {
-            row[j] = (row[j] << tail) | (row[j - 1] >> (8 - tail));
+            row[j] = (row[j] << tail) | row[j - 1].wrapping_shr((8 - tail) as u32);
         }
+    }
 
-        row[i / 8] |= 1 << (i % 8);
-
-        let mut b = 0u8;
-        for j in 0..SYS_N / 8 {
-            b ^= row[j] & e[j];
-        }
+    row[i / 8] |= 1 << (i % 8);
 
-        b ^= b >> 4;
-        b ^= b >> 2;
-        b ^= b >> 1;
-        b &= 1;
+    let mut b = 0u8;
+    for j in 0..SYS_N / 8 {
+        b ^= row[j] & e[j];
+    }
 
-        s[i / 8] |= b << (i % 8);
+    b ^= b >> 4;
+    b ^= b >> 2;
+    b ^= b >> 1;
+    b &= 1;
 
-        pk_segment = &pk_segment[PK_ROW_BYTES..];
-    }
+    s[i / 8] |= b << (i % 8);
 }
 
-/// Syndrome computation.
-///
-/// Computes syndrome `s` based on public key `pk` and error vector `e`.
-#[cfg(not(any(feature = "mceliece6960119", feature = "mceliece6960119f")))]
-fn syndrome(
-    s: &mut [u8; (PK_NROWS + 7) / 8],
+/// Encryption routine.
+/// Takes a public key `pk` and an error vector `e` generated by `gen_e` to compute syndrome `s`.
+pub(crate) fn encrypt(
+    s: &mut [u8; CRYPTO_CIPHERTEXTBYTES],
     pk: &[u8; PK_NROWS * PK_ROW_BYTES],
     e: &[u8; SYS_N / 8],
 ) {
-    let mut row = [0u8; SYS_N / 8];
+    syndrome(sub!(mut s, 0, PK_NROWS.div_ceil(8)), pk, e);
+}
 
-    let mut pk_segment = &pk[..];
+#[cfg(test)]
+variant_tests! {
+    [mceliece8192128pcf]
 
-    s[0..SYND_BYTES].fill(0);
+    mod tests {
+        use super::super::CRYPTO_PUBLICKEYBYTES;
+        use super::*;
+        use crate::randombytes::{AesState, RNGState};
 
-    for i in 0..PK_NROWS {
-        row[0..SYS_N / 8].fill(0);
+        // the test data originates from round 3, which corresponds to the `pc` variants
+        #[test]
+        fn test_encrypt() -> Result<(), Error> {
+            let entropy_input = [
+                6, 21, 80, 35, 77, 21, 140, 94, 201, 85, 149, 254, 4, 239, 122, 37, 118, 127, 46,
+                36, 204, 43, 196, 121, 208, 157, 134, 220, 154, 188, 253, 231, 5, 106, 140, 38,
+                111, 158, 249, 126, 208, 133, 65, 219, 210, 225, 255, 161,
+            ];
 
-        for j in 0..PK_ROW_BYTES {
-            row[SYS_N / 8 - PK_ROW_BYTES + j] = pk_segment[j];
-        }
+            let mut rng_state = AesState::new();
+            rng_state.randombytes_init(entropy_input);
 
-        row[i / 8] |= 1 << (i % 8);
+            let mut second_seed = [0u8; 33];
+            second_seed[0] = 64;
 
-        let mut b = 0u8;
-        for j in 0..SYS_N / 8 {
-            b ^= row[j] & e[j];
-        }
+            rng_state.randombytes(&mut second_seed[1..])?;
 
-        b ^= b >> 4;
-        b ^= b >> 2;
-        b ^= b >> 1;
-        b &= 1;
+            let mut two_e = [0u8; 1 + SYS_N / 8];
+            two_e[0] = 2;
 
-        s[i / 8] |= b << (i % 8);
+            let mut c = [0u8; CRYPTO_CIPHERTEXTBYTES];
+            let mut pk = crate::TestData::new().u8vec("mceliece8192128f_pk1");
 
-        pk_segment = &pk_segment[PK_ROW_BYTES..];
-    }
-}
+            let compare_ct = crate::TestData::new().u8vec("mceliece8192128f_encrypt_ct");
+            assert_eq!(compare_ct.len(), CRYPTO_CIPHERTEXTBYTES);
 
-/// Encryption routine.
-/// Takes a public key `pk` to compute error vector `e` and syndrome `s`.
-pub(crate) fn encrypt(
-    s: &mut [u8; CRYPTO_CIPHERTEXTBYTES],
-    pk: &[u8; PK_NROWS * PK_ROW_BYTES],
-    e: &mut [u8; SYS_N / 8],
-    rng: &mut impl RNGState,
-) -> Result<(), Box<dyn error::Error>> {
-    gen_e(e, rng)?;
-    syndrome(sub!(mut s, 0, (PK_NROWS + 7) / 8), pk, e);
-    Ok(())
-}
+            gen_e(sub!(mut two_e, 1, SYS_N / 8), &mut rng_state)?;
+            encrypt(
+                &mut c,
+                sub!(mut pk, 0, CRYPTO_PUBLICKEYBYTES),
+                sub!(two_e, 1, SYS_N / 8),
+            );
 
-#[cfg(test)]
-mod tests {
-    #[cfg(all(feature = "mceliece8192128f", test))]
-    use super::*;
-    #[cfg(all(feature = "mceliece8192128f", test))]
-    use crate::api::CRYPTO_CIPHERTEXTBYTES;
-    #[cfg(all(feature = "mceliece8192128f", test))]
-    use crate::api::CRYPTO_PUBLICKEYBYTES;
-    #[cfg(all(feature = "mceliece8192128f", test))]
-    use crate::randombytes::AesState;
-
-    #[test]
-    #[cfg(feature = "mceliece8192128f")]
-    fn test_encrypt() -> Result<(), Box<dyn error::Error>> {
-        let entropy_input = [
-            6, 21, 80, 35, 77, 21, 140, 94, 201, 85, 149, 254, 4, 239, 122, 37, 118, 127, 46, 36,
-            204, 43, 196, 121, 208, 157, 134, 220, 154, 188, 253, 231, 5, 106, 140, 38, 111, 158,
-            249, 126, 208, 133, 65, 219, 210, 225, 255, 161,
-        ];
-
-        let mut rng_state = AesState::new();
-        rng_state.randombytes_init(entropy_input);
-
-        let mut second_seed = [0u8; 33];
-        second_seed[0] = 64;
-
-        rng_state.randombytes(&mut second_seed[1..])?;
-
-        let mut two_e = [0u8; 1 + SYS_N / 8];
-        two_e[0] = 2;
-
-        let mut c = [0u8; CRYPTO_CIPHERTEXTBYTES];
-        let mut pk = crate::TestData::new().u8vec("mceliece8192128f_pk1");
-
-        let compare_ct = crate::TestData::new().u8vec("mceliece8192128f_encrypt_ct");
-        assert_eq!(compare_ct.len(), CRYPTO_CIPHERTEXTBYTES);
-
-        encrypt(
-            &mut c,
-            sub!(mut pk, 0, CRYPTO_PUBLICKEYBYTES),
-            sub!(mut two_e, 1, SYS_N / 8),
-            &mut rng_state,
-        )?;
-
-        assert_eq!(compare_ct, c);
-
-        Ok(())
+            assert_eq!(compare_ct, c);
+
+            Ok(())
+        }
     }
 }
@@ -0,0 +1,188 @@
+//! Error type returned by the fallible functions of this crate
+
+use core::fmt;
+use core::num::NonZeroU32;
+
+/// Error returned by the KEM API
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// A buffer does not have the length required by the variant
+    InvalidLength { expected: usize, actual: usize },
+    /// The random number generator failed to provide random bytes
+    RngFailure,
+    /// A deterministic random number generator was used before being seeded
+    UnseededRng,
+    /// The padding bits of a public key or ciphertext are not all zero
+    InvalidPadding,
+    /// The name does not denote any variant implemented by this crate
+    UnknownVariant,
+    /// The name denotes different variants in NIST round 3 and round 4
+    AmbiguousVariant,
+    /// The secret key is malformed and does not determine a public key
+    InvalidSecretKey,
+    /// The public key matrix is degenerate
+    InvalidPublicKey,
+    /// A string is not a valid hex encoding
+    InvalidEncoding,
+    /// An error vector does not have the Hamming weight required by the variant
+    InvalidWeight { expected: usize, actual: usize },
+    /// The coefficient at `index` is not an element of GF(2^m)
+    InvalidCoefficient { index: usize },
+    /// A polynomial does not have the degree required by the operation
+    InvalidDegree { expected: usize, actual: usize },
+    /// An element of GF((2^m)^t) lies in a proper subfield, so its minimal polynomial has degree less than t
+    SubfieldElement,
+    /// The operation needs heap-allocated buffers, but the `alloc` feature is disabled
+    RequiresAlloc,
+    /// Reading the input failed
+    #[cfg(feature = "std")]
+    Io(std::io::ErrorKind),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        Error::Io(err.kind())
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::InvalidLength { expected, actual } => write!(
+                f,
+                "invalid length: expected {} bytes, got {} bytes",
+                expected, actual
+            ),
+            Error::RngFailure => write!(f, "random number generator failed"),
+            Error::UnseededRng => write!(f, "random number generator has not been seeded"),
+            Error::InvalidPadding => write!(f, "padding bits are not all zero"),
+            Error::UnknownVariant => write!(f, "unknown Classic McEliece variant"),
+            Error::AmbiguousVariant => {
+                write!(f, "variant name differs between NIST rounds 3 and 4")
+            }
+            Error::InvalidSecretKey => write!(f, "secret key is malformed"),
+            Error::InvalidPublicKey => write!(f, "public key is degenerate"),
+            Error::InvalidEncoding => write!(f, "invalid hex encoding"),
+            Error::InvalidWeight { expected, actual } => write!(
+                f,
+                "invalid error vector: expected weight {}, got weight {}",
+                expected, actual
+            ),
+            Error::InvalidCoefficient { index } => {
+                write!(f, "coefficient {} is not an element of GF(2^m)", index)
+            }
+            Error::InvalidDegree { expected, actual } => write!(
+                f,
+                "invalid polynomial: expected degree {}, got degree {}",
+                expected, actual
+            ),
+            Error::SubfieldElement => write!(f, "field element lies in a proper subfield"),
+            Error::RequiresAlloc => write!(f, "operation requires the `alloc` feature"),
+            #[cfg(feature = "std")]
+            Error::Io(kind) => write!(f, "I/O error: {}", kind),
+        }
+    }
+}
+
+/// Codes of RNG failures passed through `rand_core::Error`, in the range reserved for custom errors
+const RNG_FAILURE_CODE: u32 = rand_core::Error::CUSTOM_START;
+const UNSEEDED_RNG_CODE: u32 = rand_core::Error::CUSTOM_START + 1;
+
+impl From<rand_core::Error> for Error {
+    fn from(err: rand_core::Error) -> Self {
+        match err.code() {
+            Some(code) if code.get() == UNSEEDED_RNG_CODE => Error::UnseededRng,
+            _ => Error::RngFailure,
+        }
+    }
+}
+
+impl From<Error> for rand_core::Error {
+    fn from(err: Error) -> Self {
+        let code = match err {
+            Error::UnseededRng => UNSEEDED_RNG_CODE,
+            _ => RNG_FAILURE_CODE,
+        };
+        rand_core::Error::from(NonZeroU32::new(code).unwrap())
+    }
+}
+
+/// Interpret `bytes` as array of `N` bytes or return `Error::InvalidLength`
+pub(crate) fn as_array<const N: usize>(bytes: &[u8]) -> Result<&[u8; N], Error> {
+    bytes.try_into().map_err(|_| Error::InvalidLength {
+        expected: N,
+        actual: bytes.len(),
+    })
+}
+
+/// Interpret `bytes` as mutable array of `N` bytes or return `Error::InvalidLength`
+pub(crate) fn as_array_mut<const N: usize>(bytes: &mut [u8]) -> Result<&mut [u8; N], Error> {
+    let actual = bytes.len();
+    bytes.try_into().map_err(|_| Error::InvalidLength {
+        expected: N,
+        actual,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_as_array() {
+        let mut data = [1u8, 2, 3, 4];
+
+        assert_eq!(as_array::<4>(&data), Ok(&[1u8, 2, 3, 4]));
+        assert_eq!(
+            as_array::<5>(&data),
+            Err(Error::InvalidLength {
+                expected: 5,
+                actual: 4
+            })
+        );
+        assert_eq!(as_array_mut::<4>(&mut data).map(|a| a[3]), Ok(4));
+        assert_eq!(
+            as_array_mut::<2>(&mut data[1..]),
+            Err(Error::InvalidLength {
+                expected: 2,
+                actual: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let err = Error::InvalidLength {
+            expected: 32,
+            actual: 31,
+        };
+        assert_eq!(
+            err.to_string(),
+            "invalid length: expected 32 bytes, got 31 bytes"
+        );
+        assert_eq!(
+            Error::InvalidPadding.to_string(),
+            "padding bits are not all zero"
+        );
+        assert_eq!(
+            Error::InvalidCoefficient { index: 3 }.to_string(),
+            "coefficient 3 is not an element of GF(2^m)"
+        );
+        assert_eq!(
+            Error::RequiresAlloc.to_string(),
+            "operation requires the `alloc` feature"
+        );
+    }
+
+    #[test]
+    fn test_rand_core_roundtrip() {
+        for err in [Error::RngFailure, Error::UnseededRng] {
+            assert_eq!(Error::from(rand_core::Error::from(err)), err);
+        }
+    }
+}
@@ -0,0 +1,320 @@
+//! Elements of the binary fields GF(2^12) and GF(2^13) underlying the Goppa codes
+//!
+//! The arithmetic is carried out by the constant-time routines of the variant modules:
+//! `mceliece348864*` use GF(2^12) defined by z^12 + z^3 + 1,
+//! all other variants use GF(2^13) defined by z^13 + z^4 + z^3 + z + 1.
+
+use core::fmt;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// Implements a field element type on top of the `gf` module of the variant `$module`
+macro_rules! field_element {
+    ($name:ident, $module:ident, $bits:expr, $doc:expr) => {
+        #[doc = $doc]
+        ///
+        /// An element is a polynomial over GF(2) of degree less than `BITS`, stored in the low `BITS` bits of a `u16`.
+        /// Addition and subtraction are both XOR. All operations run in constant time.
+        /// As in the reference implementation, zero is mapped to zero by inversion
+        /// and division by zero yields zero instead of panicking.
+        #[derive(Clone, Copy, Default)]
+        pub struct $name(u16);
+
+        impl $name {
+            /// Degree of the field over GF(2)
+            pub const BITS: usize = $bits;
+            /// Number of elements of the field
+            pub const ORDER: usize = 1 << $bits;
+            /// The additive identity
+            pub const ZERO: $name = $name(0);
+            /// The multiplicative identity
+            pub const ONE: $name = $name(1);
+
+            /// Returns the element with bit representation `value` or `None` if `value` has more than `BITS` bits
+            pub const fn new(value: u16) -> Option<$name> {
+                if (value as usize) < Self::ORDER {
+                    Some($name(value))
+                } else {
+                    None
+                }
+            }
+
+            /// Returns the bit representation of the element
+            pub const fn value(self) -> u16 {
+                self.0
+            }
+
+            /// Returns the multiplicative inverse of a non-zero element and zero for zero
+            pub fn inv(self) -> $name {
+                $name(crate::$module::gf::gf_inv(self.0))
+            }
+
+            /// Returns `self * self`
+            pub fn square(self) -> $name {
+                self * self
+            }
+
+            /// Returns `self` raised to the power `exp`, where `0^0 = 1`.
+            /// The running time depends neither on `self` nor on `exp`.
+            pub fn pow(self, exp: u32) -> $name {
+                let mut result = $name::ONE;
+
+                for i in (0..u32::BITS).rev() {
+                    result = result.square();
+                    let product = result * self;
+                    let mask = 0u16.wrapping_sub(((exp >> i) & 1) as u16);
+                    result = $name(result.0 ^ (mask & (result.0 ^ product.0)));
+                }
+
+                result
+            }
+
+            /// Returns 0xFF if `self` and `other` are equal and 0 otherwise, in constant time
+            pub fn ct_eq(self, other: $name) -> u8 {
+                let mut t = (self.0 ^ other.0) as u32;
+                t = t.wrapping_sub(1);
+                t >>= 31;
+                0u8.wrapping_sub(t as u8)
+            }
+
+            /// Returns 0xFF if `self` is zero and 0 otherwise, in constant time
+            pub fn ct_is_zero(self) -> u8 {
+                self.ct_eq($name::ZERO)
+            }
+        }
+
+        impl PartialEq for $name {
+            fn eq(&self, other: &$name) -> bool {
+                self.ct_eq(*other) != 0
+            }
+        }
+
+        impl Eq for $name {}
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}({:#06x})", stringify!($name), self.0)
+            }
+        }
+
+        impl From<$name> for u16 {
+            fn from(element: $name) -> u16 {
+                element.0
+            }
+        }
+
+        impl Add for $name {
+            type Output = $name;
+
+            fn add(self, rhs: $name) -> $name {
+                $name(crate::$module::gf::gf_add(self.0, rhs.0))
+            }
+        }
+
+        impl Sub for $name {
+            type Output = $name;
+
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            fn sub(self, rhs: $name) -> $name {
+                self + rhs
+            }
+        }
+
+        impl Neg for $name {
+            type Output = $name;
+
+            fn neg(self) -> $name {
+                self
+            }
+        }
+
+        impl Mul for $name {
+            type Output = $name;
+
+            fn mul(self, rhs: $name) -> $name {
+                $name(crate::$module::gf::gf_mul(self.0, rhs.0))
+            }
+        }
+
+        impl Div for $name {
+            type Output = $name;
+
+            fn div(self, rhs: $name) -> $name {
+                $name(crate::$module::gf::gf_frac(rhs.0, self.0))
+            }
+        }
+
+        impl AddAssign for $name {
+            fn add_assign(&mut self, rhs: $name) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl SubAssign for $name {
+            fn sub_assign(&mut self, rhs: $name) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl MulAssign for $name {
+            fn mul_assign(&mut self, rhs: $name) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl DivAssign for $name {
+            fn div_assign(&mut self, rhs: $name) {
+                *self = *self / rhs;
+            }
+        }
+    };
+}
+
+field_element!(
+    Gf12,
+    mceliece348864,
+    12,
+    "An element of GF(2^12), the field of the `mceliece348864` variants"
+);
+field_element!(
+    Gf13,
+    mceliece460896,
+    13,
+    "An element of GF(2^13), the field of all variants except `mceliece348864`"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    /// Checks the field axioms for random elements and inversion exhaustively
+    macro_rules! field_tests {
+        ($name:ident, $test_axioms:ident, $test_inv:ident, $test_pow:ident) => {
+            fn random_element(rng: &mut impl Rng) -> $name {
+                $name::new(rng.gen_range(0..$name::ORDER as u16)).unwrap()
+            }
+
+            #[test]
+            fn $test_axioms() {
+                let mut rng = rand::thread_rng();
+
+                for _ in 0..2000 {
+                    let (a, b, c) = (
+                        random_element(&mut rng),
+                        random_element(&mut rng),
+                        random_element(&mut rng),
+                    );
+
+                    assert_eq!(a + b, b + a, "a = {:?}, b = {:?}", a, b);
+                    assert_eq!(a * b, b * a, "a = {:?}, b = {:?}", a, b);
+                    assert_eq!((a + b) + c, a + (b + c), "{:?} {:?} {:?}", a, b, c);
+                    assert_eq!((a * b) * c, a * (b * c), "{:?} {:?} {:?}", a, b, c);
+                    assert_eq!(a * (b + c), a * b + a * c, "{:?} {:?} {:?}", a, b, c);
+                    assert_eq!(a + $name::ZERO, a);
+                    assert_eq!(a * $name::ONE, a);
+                    assert_eq!(a * $name::ZERO, $name::ZERO);
+                    assert_eq!(a + (-a), $name::ZERO);
+                    assert_eq!(a - b, a + b);
+                    assert!((a * b).value() < $name::ORDER as u16);
+
+                    if b != $name::ZERO {
+                        assert_eq!((a / b) * b, a, "a = {:?}, b = {:?}", a, b);
+                    }
+
+                    let mut x = a;
+                    x += b;
+                    x *= c;
+                    x -= a * c;
+                    assert_eq!(x, b * c);
+                    if c != $name::ZERO {
+                        x /= c;
+                        assert_eq!(x, b);
+                    }
+                }
+            }
+
+            #[test]
+            fn $test_inv() {
+                assert_eq!($name::ZERO.inv(), $name::ZERO);
+                assert_eq!($name::ONE / $name::ZERO, $name::ZERO);
+
+                for value in 1..$name::ORDER as u16 {
+                    let a = $name::new(value).unwrap();
+                    assert_eq!(a * a.inv(), $name::ONE, "a = {:?}", a);
+                    assert_eq!($name::ONE / a, a.inv());
+                }
+
+                assert_eq!($name::new($name::ORDER as u16), None);
+                assert_eq!($name::new(u16::MAX), None);
+            }
+
+            #[test]
+            fn $test_pow() {
+                let mut rng = rand::thread_rng();
+                let group_order = $name::ORDER as u32 - 1;
+
+                assert_eq!($name::ZERO.pow(0), $name::ONE);
+                assert_eq!($name::ZERO.pow(5), $name::ZERO);
+
+                for _ in 0..200 {
+                    let a = random_element(&mut rng);
+                    let exp = rng.gen_range(0..64);
+
+                    let mut expected = $name::ONE;
+                    for _ in 0..exp {
+                        expected *= a;
+                    }
+                    assert_eq!(a.pow(exp), expected, "a = {:?}, exp = {}", a, exp);
+                    assert_eq!(a.pow(2), a.square());
+
+                    // Fermat's little theorem and the Frobenius automorphism
+                    assert_eq!(a.pow(group_order + 1), a);
+                    if a != $name::ZERO {
+                        assert_eq!(a.pow(group_order - 1), a.inv());
+                    }
+                    let b = random_element(&mut rng);
+                    assert_eq!((a + b).square(), a.square() + b.square());
+                }
+            }
+        };
+    }
+
+    mod gf12 {
+        use super::*;
+        field_tests!(Gf12, test_field_axioms, test_inv, test_pow);
+    }
+
+    mod gf13 {
+        use super::*;
+        field_tests!(Gf13, test_field_axioms, test_inv, test_pow);
+    }
+
+    #[test]
+    fn test_ct_eq() {
+        let a = Gf13::new(0x1234).unwrap();
+        assert_eq!(a.ct_eq(a), 0xFF);
+        assert_eq!(a.ct_eq(Gf13::ONE), 0);
+        assert_eq!(Gf13::ZERO.ct_is_zero(), 0xFF);
+        assert_eq!(a.ct_is_zero(), 0);
+        assert_eq!(Gf12::new(0xFFF).unwrap().ct_eq(Gf12::ZERO), 0);
+    }
+
+    #[test]
+    fn test_known_products() {
+        // z^11 * z = z^12 = z^3 + 1 in GF(2^12)
+        assert_eq!(
+            Gf12::new(0x800).unwrap() * Gf12::new(2).unwrap(),
+            Gf12::new(0x9).unwrap()
+        );
+        // z^12 * z = z^13 = z^4 + z^3 + z + 1 in GF(2^13)
+        assert_eq!(
+            Gf13::new(0x1000).unwrap() * Gf13::new(2).unwrap(),
+            Gf13::new(0x1B).unwrap()
+        );
+        assert_eq!(
+            u16::from(Gf12::new(125).unwrap() * Gf12::new(19).unwrap()),
+            1879
+        );
+        assert_eq!(format!("{:?}", Gf13::new(0x1B).unwrap()), "Gf13(0x001b)");
+    }
+}
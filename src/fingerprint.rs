@@ -0,0 +1,161 @@
+//! Short identifiers of public keys
+
+use core::fmt;
+use core::str::FromStr;
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+use crate::crypto_hash::Shake256Stream;
+use crate::error::{as_array, Error};
+
+/// Length of a fingerprint in bytes
+pub const FINGERPRINT_BYTES: usize = 32;
+
+/// Domain separation prefix of the fingerprint hash, followed by the name of the variant and a zero byte
+const DOMAIN: &[u8] = b"classic-mceliece-rust public key fingerprint v1\0";
+
+/// A fixed-length identifier of a public key.
+///
+/// The fingerprint is SHAKE256 of a domain separation prefix, the name of the variant and the key bytes.
+/// Hence keys of different variants never share a fingerprint even if their bytes coincide.
+/// `Display` and `FromStr` use the lowercase hex form of `2 * FINGERPRINT_BYTES` characters.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fingerprint([u8; FINGERPRINT_BYTES]);
+
+impl Fingerprint {
+    /// Copies the fingerprint from `bytes`, which must have length `FINGERPRINT_BYTES`
+    pub fn from_bytes(bytes: &[u8]) -> Result<Fingerprint, Error> {
+        Ok(Fingerprint(*as_array(bytes)?))
+    }
+
+    /// Returns the bytes of the fingerprint
+    pub fn as_bytes(&self) -> &[u8; FINGERPRINT_BYTES] {
+        &self.0
+    }
+
+    /// Returns the lowercase hex form of the fingerprint
+    #[cfg(feature = "alloc")]
+    pub fn to_hex(&self) -> String {
+        use alloc::string::ToString;
+        self.to_string()
+    }
+}
+
+impl fmt::Display for Fingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for b in self.0 {
+            write!(f, "{:02x}", b)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Fingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Fingerprint({})", self)
+    }
+}
+
+impl FromStr for Fingerprint {
+    type Err = Error;
+
+    /// Parses the hex form, upper- and lowercase digits are accepted
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() != 2 * FINGERPRINT_BYTES {
+            return Err(Error::InvalidLength {
+                expected: 2 * FINGERPRINT_BYTES,
+                actual: s.len(),
+            });
+        }
+
+        let mut bytes = [0u8; FINGERPRINT_BYTES];
+        for (byte, pair) in bytes.iter_mut().zip(s.as_bytes().chunks(2)) {
+            *byte = hex_digit(pair[0])? << 4 | hex_digit(pair[1])?;
+        }
+
+        Ok(Fingerprint(bytes))
+    }
+}
+
+/// Value of the hex digit `c`
+fn hex_digit(c: u8) -> Result<u8, Error> {
+    match c {
+        b'0'..=b'9' => Ok(c - b'0'),
+        b'a'..=b'f' => Ok(c - b'a' + 10),
+        b'A'..=b'F' => Ok(c - b'A' + 10),
+        _ => Err(Error::InvalidEncoding),
+    }
+}
+
+/// Incremental computation of the fingerprint of a public key of variant `name`
+pub(crate) struct FingerprintHasher(Shake256Stream);
+
+impl FingerprintHasher {
+    pub(crate) fn new(name: &str) -> Self {
+        let mut stream = Shake256Stream::new();
+        stream.update(DOMAIN);
+        stream.update(name.as_bytes());
+        stream.update(&[0]);
+        FingerprintHasher(stream)
+    }
+
+    /// Absorb the next bytes of the public key
+    pub(crate) fn update(&mut self, pk: &[u8]) {
+        self.0.update(pk);
+    }
+
+    pub(crate) fn finalize(self) -> Fingerprint {
+        let mut fingerprint = Fingerprint([0u8; FINGERPRINT_BYTES]);
+        self.0.finalize(&mut fingerprint.0);
+        fingerprint
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hex_roundtrip() {
+        let mut hasher = FingerprintHasher::new("mceliece348864");
+        hasher.update(&[1, 2, 3]);
+        let fingerprint = hasher.finalize();
+
+        let hex = fingerprint.to_string();
+        assert_eq!(hex.len(), 2 * FINGERPRINT_BYTES);
+        assert!(hex
+            .bytes()
+            .all(|c| c.is_ascii_digit() || (b'a'..=b'f').contains(&c)));
+        assert_eq!(hex.parse::<Fingerprint>(), Ok(fingerprint));
+        assert_eq!(hex.to_uppercase().parse::<Fingerprint>(), Ok(fingerprint));
+        assert_eq!(
+            Fingerprint::from_bytes(fingerprint.as_bytes()),
+            Ok(fingerprint)
+        );
+    }
+
+    #[test]
+    fn test_invalid_hex() {
+        assert_eq!(
+            "00".parse::<Fingerprint>(),
+            Err(Error::InvalidLength {
+                expected: 64,
+                actual: 2
+            })
+        );
+        let s = "g".repeat(64);
+        assert_eq!(s.parse::<Fingerprint>(), Err(Error::InvalidEncoding));
+        let s = "+1".repeat(32);
+        assert_eq!(s.parse::<Fingerprint>(), Err(Error::InvalidEncoding));
+    }
+
+    #[test]
+    fn test_domain_separation() {
+        let mut a = FingerprintHasher::new("mceliece348864");
+        let mut b = FingerprintHasher::new("mceliece348864f");
+        a.update(&[0u8; 16]);
+        b.update(&[0u8; 16]);
+        assert_ne!(a.finalize(), b.finalize());
+    }
+}
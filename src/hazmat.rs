@@ -0,0 +1,251 @@
+//! Primitives underlying the KEM (requires the `hazmat` feature)
+//!
+//! # Hazardous material
+//!
+//! These functions expose Niederreiter encryption and the decoding of binary Goppa codes
+//! for research on decoders and attacks. They do not provide IND-CCA2 security on their own:
+//! without the hashing and implicit rejection of `crypto_kem_enc` and `crypto_kem_dec`,
+//! syndromes and error vectors are malleable and decoding failures are observable.
+//! Use the KEM functions of the variant module for anything else.
+//!
+//! Field elements of GF(2^`GFBITS`) are stored as [`Gf`] in the low `GFBITS` bits.
+//! Polynomials are arrays of coefficients with the constant term first.
+
+use super::{
+    benes::support_gen,
+    params::{COND_BYTES, IRR_BYTES, PK_NROWS, PK_ROW_BYTES},
+    util::load_gf,
+    CRYPTO_PUBLICKEYBYTES, CRYPTO_SECRETKEYBYTES,
+};
+#[cfg(feature = "alloc")]
+use super::{
+    types::{Ciphertext, SecretKey, SharedSecret},
+    CRYPTO_BYTES,
+};
+#[cfg(feature = "alloc")]
+use crate::error::Error;
+use crate::macros::sub;
+
+pub use super::gf::Gf;
+pub use super::operations::crypto_kem_dec_with_status;
+pub use super::params::{GFBITS, SYND_BYTES, SYS_N, SYS_T};
+
+/// Offset of the Goppa polynomial within the secret key
+const SK_IRR_OFFSET: usize = 40;
+
+/// KEM Decapsulation reporting implicit rejection, returning the shared secret by value.
+///
+/// See `crypto_kem_dec_with_status`.
+#[cfg(feature = "alloc")]
+pub fn decapsulate_with_status(
+    ct: &Ciphertext,
+    sk: &SecretKey,
+) -> Result<(SharedSecret, u8), Error> {
+    let mut ss = SharedSecret([0u8; CRYPTO_BYTES]);
+
+    let status = crypto_kem_dec_with_status(&mut ss.0, &ct.0, &sk.0)?;
+
+    Ok((ss, status))
+}
+
+/// Niederreiter encryption: computes the syndrome `s` of the error vector `e` of `SYS_N` bits
+/// with respect to the public key `pk`.
+///
+/// Hazmat: `e` is encrypted as given. The KEM only encrypts vectors of weight `SYS_T`.
+pub fn encrypt(s: &mut [u8; SYND_BYTES], pk: &[u8; CRYPTO_PUBLICKEYBYTES], e: &[u8; SYS_N / 8]) {
+    super::encrypt::syndrome(s, sub!(pk, 0, PK_NROWS * PK_ROW_BYTES), e);
+}
+
+/// Niederreiter decryption: decodes the syndrome `s` with the secret key `sk` into the error vector `e`.
+///
+/// Returns `true` if an error vector of weight `SYS_T` with syndrome `s` was found.
+/// Hazmat: unlike `crypto_kem_dec`, the outcome is returned in the clear.
+pub fn decrypt(
+    e: &mut [u8; SYS_N / 8],
+    sk: &[u8; CRYPTO_SECRETKEYBYTES],
+    s: &[u8; SYND_BYTES],
+) -> bool {
+    super::decrypt::decrypt(e, sub!(sk, SK_IRR_OFFSET, IRR_BYTES + COND_BYTES), s) == 0
+}
+
+/// Computes the `2 * SYS_T` syndrome values `out` of the received word `r` of `SYS_N` bits
+/// for the Goppa polynomial `f` and the support `l`.
+pub fn synd(out: &mut [Gf; SYS_T * 2], f: &[Gf; SYS_T + 1], l: &[Gf; SYS_N], r: &[u8; SYS_N / 8]) {
+    super::synd::synd(out, f, l, r);
+}
+
+/// The Berlekamp-Massey algorithm: computes the error locator polynomial `out`
+/// of the syndrome values `s`.
+pub fn bm(out: &mut [Gf; SYS_T + 1], s: &[Gf; SYS_T * 2]) {
+    let mut s = *s;
+    super::bm::bm(out, &mut s);
+}
+
+/// Evaluates the polynomial `f` at every element of `l`, such that `out[i] = f(l[i])`.
+/// The roots of the error locator polynomial mark the error positions.
+pub fn root(out: &mut [Gf; SYS_N], f: &[Gf; SYS_T + 1], l: &[Gf; SYS_N]) {
+    super::root::root(out, f, l);
+}
+
+/// Evaluates the polynomial `f` at `a`.
+pub fn eval(f: &[Gf; SYS_T + 1], a: Gf) -> Gf {
+    super::root::eval(f, a)
+}
+
+/// Extracts the monic Goppa polynomial `g` of degree `SYS_T` from the secret key `sk`.
+///
+/// Hazmat: `g` is secret.
+pub fn goppa_polynomial(g: &mut [Gf; SYS_T + 1], sk: &[u8; CRYPTO_SECRETKEYBYTES]) {
+    let irr = sub!(sk, SK_IRR_OFFSET, IRR_BYTES);
+
+    for (i, chunk) in irr.chunks(2).enumerate() {
+        g[i] = load_gf(sub!(chunk, 0, 2));
+    }
+    g[SYS_T] = 1;
+}
+
+/// Computes the support `l` = (α_0, …, α_{n-1}) from the control bits stored in the secret key `sk`,
+/// as done by `support_gen` during decryption.
+///
+/// Hazmat: the support is secret.
+pub fn support(l: &mut [Gf; SYS_N], sk: &[u8; CRYPTO_SECRETKEYBYTES]) {
+    support_gen(l, sub!(sk, SK_IRR_OFFSET + IRR_BYTES, COND_BYTES));
+}
+
+// the keys of the larger variants do not fit on the stack of the test threads without `alloc`
+#[cfg(all(test, feature = "alloc"))]
+variant_tests! {
+    [mceliece348864, mceliece348864pc, mceliece460896f, mceliece6960119]
+
+    mod tests {
+        use super::*;
+        use crate::randombytes::{AesState, RNGState};
+
+        variant_tests! {
+            [mceliece348864, mceliece348864pc, mceliece6960119]
+
+            use super::super::{
+                operations::{decapsulate, encapsulate, expand_secret_key},
+                params::PLAINTEXT_CONFIRMATION,
+                CRYPTO_CIPHERTEXTBYTES,
+            };
+
+            #[test]
+            fn test_decapsulate_with_status() -> Result<(), Error> {
+                let (pk, sk) = expand_secret_key(&[12u8; 32]);
+                let mut rng = AesState::new();
+                rng.randombytes_init([13u8; 48]);
+                let (ct, ss) = encapsulate(&pk, &mut rng)?;
+
+                let (ss_status, status) = decapsulate_with_status(&ct, &sk)?;
+                assert_eq!(status, 0xFF);
+                assert_eq!(ss_status.as_bytes(), ss.as_bytes());
+
+                // an altered syndrome is implicitly rejected, the key is the same as the one of
+                // `decapsulate`
+                let mut ct_bytes = *ct.as_bytes();
+                ct_bytes[0] ^= 1;
+                let ct_altered = Ciphertext(ct_bytes);
+                let (ss_status, status) = decapsulate_with_status(&ct_altered, &sk)?;
+                assert_eq!(status, 0);
+                assert_eq!(
+                    ss_status.as_bytes(),
+                    decapsulate(&ct_altered, &sk)?.as_bytes()
+                );
+                assert_ne!(ss_status.as_bytes(), ss.as_bytes());
+
+                if PLAINTEXT_CONFIRMATION {
+                    let mut ct_bytes = *ct.as_bytes();
+                    ct_bytes[CRYPTO_CIPHERTEXTBYTES - 1] ^= 1;
+                    let mut key = [0u8; CRYPTO_BYTES];
+                    assert_eq!(
+                        crypto_kem_dec_with_status(&mut key, &ct_bytes, sk.as_bytes()),
+                        Ok(0)
+                    );
+                }
+
+                if SYND_BYTES * 8 != PK_NROWS {
+                    let mut ct_bytes = *ct.as_bytes();
+                    ct_bytes[SYND_BYTES - 1] |= 0x80;
+                    assert_eq!(
+                        decapsulate_with_status(&Ciphertext(ct_bytes), &sk).err(),
+                        Some(Error::InvalidPadding)
+                    );
+                }
+
+                Ok(())
+            }
+        }
+
+        variant_tests! {
+            [mceliece348864, mceliece460896f, mceliece6960119]
+
+            use super::super::{
+                encrypt::gen_e,
+                gf::gf_iszero,
+                operations::crypto_kem_keypair_from_seed,
+                util::{large_array, LargeArray},
+            };
+
+            #[test]
+            fn test_decode_pipeline() {
+                let mut pk: LargeArray<u8, CRYPTO_PUBLICKEYBYTES> = large_array(0u8);
+                let mut sk: LargeArray<u8, CRYPTO_SECRETKEYBYTES> = large_array(0u8);
+                crypto_kem_keypair_from_seed(&mut pk, &mut sk, &[9u8; 32]);
+
+                let mut rng = AesState::new();
+                rng.randombytes_init([10u8; 48]);
+                let mut e = [0u8; SYS_N / 8];
+                gen_e(&mut e, &mut rng).unwrap();
+
+                let mut s = [0u8; SYND_BYTES];
+                encrypt(&mut s, &pk, &e);
+
+                let mut e_decrypted = [0u8; SYS_N / 8];
+                assert!(decrypt(&mut e_decrypted, &sk, &s));
+                assert_eq!(e_decrypted, e);
+
+                // decoding step by step
+                let mut g = [0; SYS_T + 1];
+                let mut l = [0; SYS_N];
+                goppa_polynomial(&mut g, &sk);
+                support(&mut l, &sk);
+
+                for i in 1..SYS_N {
+                    assert_ne!(l[i], l[0]);
+                    assert_ne!(eval(&g, l[i]), 0);
+                }
+
+                // the syndrome of the error vector equals the syndrome of the ciphertext padded
+                // with zeros
+                let mut r = [0u8; SYS_N / 8];
+                r[..SYND_BYTES].copy_from_slice(&s);
+                let mut synd_r = [0; SYS_T * 2];
+                let mut synd_e = [0; SYS_T * 2];
+                synd(&mut synd_r, &g, &l, &r);
+                synd(&mut synd_e, &g, &l, &e);
+                assert_eq!(synd_r, synd_e);
+
+                let mut locator = [0; SYS_T + 1];
+                bm(&mut locator, &synd_r);
+
+                let mut images = [0; SYS_N];
+                root(&mut images, &locator, &l);
+                for i in 0..SYS_N {
+                    let is_error = (e[i / 8] >> (i % 8)) & 1 == 1;
+                    assert_eq!(gf_iszero(images[i]) != 0, is_error);
+                    assert_eq!(images[i], eval(&locator, l[i]));
+                }
+
+                // too many errors cannot be decoded
+                let mut e_heavy = e;
+                let pos = (0..SYS_N)
+                    .find(|&i| (e[i / 8] >> (i % 8)) & 1 == 0)
+                    .unwrap();
+                e_heavy[pos / 8] |= 1 << (pos % 8);
+                encrypt(&mut s, &pk, &e_heavy);
+                assert!(!decrypt(&mut e_decrypted, &sk, &s));
+            }
+        }
+    }
+}
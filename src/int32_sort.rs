@@ -118,6 +118,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::needless_range_loop)]
     fn test_int32_sort() {
         let mut array: [i32; 64] = [0; 64];
 
@@ -131,7 +132,7 @@ mod tests {
         for i in 0..array.len() {
             //println!("{}", array[i]);
             if i >= 1 {
-                assert_eq!(array[i] > array[i - 1], true);
+                assert!(array[i] > array[i - 1]);
             }
         }
     }
@@ -0,0 +1,470 @@
+//! Runtime selection of a Classic McEliece variant
+//!
+//! Each variant module provides functions operating on arrays of fixed length.
+//! `Variant` names one of these modules at runtime and `Kem` provides
+//! its API on byte slices, so the parameter set can be chosen by configuration.
+
+use core::fmt;
+use core::str::FromStr;
+
+use rand_core::CryptoRngCore;
+
+use crate::error::{as_array, as_array_mut, Error};
+use crate::fingerprint::Fingerprint;
+
+/// The Classic McEliece parameter sets implemented by this crate
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Variant {
+    Mceliece348864,
+    Mceliece348864f,
+    Mceliece348864pc,
+    Mceliece348864pcf,
+    Mceliece460896,
+    Mceliece460896f,
+    Mceliece460896pc,
+    Mceliece460896pcf,
+    Mceliece6688128,
+    Mceliece6688128f,
+    Mceliece6688128pc,
+    Mceliece6688128pcf,
+    Mceliece6960119,
+    Mceliece6960119f,
+    Mceliece6960119pc,
+    Mceliece6960119pcf,
+    Mceliece8192128,
+    Mceliece8192128f,
+    Mceliece8192128pc,
+    Mceliece8192128pcf,
+}
+
+/// Object-safe interface to the KEM of one variant.
+/// Buffers must have exactly the lengths given by the corresponding `Variant` methods.
+pub trait Kem {
+    /// The variant implemented by this object
+    fn variant(&self) -> Variant;
+
+    /// Generate a keypair and store it in `pk` and `sk`, see `crypto_kem_keypair`
+    fn keypair(
+        &self,
+        pk: &mut [u8],
+        sk: &mut [u8],
+        rng: &mut dyn CryptoRngCore,
+    ) -> Result<(), Error>;
+
+    /// Generate the keypair determined by `seed`, see `crypto_kem_keypair_from_seed`
+    fn keypair_from_seed(&self, pk: &mut [u8], sk: &mut [u8], seed: &[u8; 32])
+        -> Result<(), Error>;
+
+    /// Recompute the public key `pk` belonging to `sk`, see `crypto_kem_public_key_from_secret_key`
+    fn public_key_from_secret_key(&self, pk: &mut [u8], sk: &[u8]) -> Result<(), Error>;
+
+    /// Compute the fingerprint of `pk`, see `public_key_fingerprint`
+    fn fingerprint(&self, pk: &[u8]) -> Result<Fingerprint, Error>;
+
+    /// Compute the fingerprint of a public key read from `reader`, see `public_key_fingerprint_from_reader`
+    #[cfg(feature = "std")]
+    fn fingerprint_from_reader(&self, reader: &mut dyn std::io::Read)
+        -> Result<Fingerprint, Error>;
+
+    /// Check the public key `pk` received from another party, see `validate_public_key`
+    fn validate_public_key(&self, pk: &[u8]) -> Result<(), Error>;
+
+    /// Check the structure of `sk`, see `validate_secret_key`
+    fn validate_secret_key(&self, sk: &[u8], check_seed: bool) -> Result<(), Error>;
+
+    /// Encapsulate a shared secret for `pk` in `ct` and store it in `ss`, see `crypto_kem_enc`
+    fn encapsulate(
+        &self,
+        ct: &mut [u8],
+        ss: &mut [u8],
+        pk: &[u8],
+        rng: &mut dyn CryptoRngCore,
+    ) -> Result<(), Error>;
+
+    /// Encapsulate a shared secret for the public key read from `reader` and return its fingerprint,
+    /// see `crypto_kem_enc_from_reader`
+    #[cfg(feature = "std")]
+    fn encapsulate_from_reader(
+        &self,
+        ct: &mut [u8],
+        ss: &mut [u8],
+        reader: &mut dyn std::io::Read,
+        rng: &mut dyn CryptoRngCore,
+    ) -> Result<Fingerprint, Error>;
+
+    /// Recover the shared secret `ss` from `ct` using `sk`, see `crypto_kem_dec`
+    fn decapsulate(&self, ss: &mut [u8], ct: &[u8], sk: &[u8]) -> Result<(), Error>;
+}
+
+macro_rules! variants {
+    ($($variant:ident => $module:ident, $kem:ident;)*) => {
+        impl Variant {
+            /// All variants in order of increasing parameter size
+            pub const ALL: [Variant; 20] = [$(Variant::$variant),*];
+
+            /// Name of the variant as stored in `CRYPTO_PRIMITIVE`
+            pub fn name(self) -> &'static str {
+                match self {
+                    $(Variant::$variant => crate::$module::CRYPTO_PRIMITIVE,)*
+                }
+            }
+
+            /// The number of bytes required to store the public key
+            pub fn public_key_bytes(self) -> usize {
+                match self {
+                    $(Variant::$variant => crate::$module::CRYPTO_PUBLICKEYBYTES,)*
+                }
+            }
+
+            /// The number of bytes required to store the secret key
+            pub fn secret_key_bytes(self) -> usize {
+                match self {
+                    $(Variant::$variant => crate::$module::CRYPTO_SECRETKEYBYTES,)*
+                }
+            }
+
+            /// The number of bytes required to store the ciphertext
+            pub fn ciphertext_bytes(self) -> usize {
+                match self {
+                    $(Variant::$variant => crate::$module::CRYPTO_CIPHERTEXTBYTES,)*
+                }
+            }
+
+            /// The number of bytes required to store the shared secret
+            pub fn shared_secret_bytes(self) -> usize {
+                match self {
+                    $(Variant::$variant => crate::$module::CRYPTO_BYTES,)*
+                }
+            }
+
+            /// Returns the KEM implementation of this variant
+            pub fn kem(self) -> &'static dyn Kem {
+                match self {
+                    $(Variant::$variant => &$kem,)*
+                }
+            }
+        }
+
+        $(
+            struct $kem;
+
+            impl Kem for $kem {
+                fn variant(&self) -> Variant {
+                    Variant::$variant
+                }
+
+                fn keypair(
+                    &self,
+                    pk: &mut [u8],
+                    sk: &mut [u8],
+                    mut rng: &mut dyn CryptoRngCore,
+                ) -> Result<(), Error> {
+                    crate::$module::crypto_kem_keypair_slice(pk, sk, &mut rng)
+                }
+
+                fn keypair_from_seed(
+                    &self,
+                    pk: &mut [u8],
+                    sk: &mut [u8],
+                    seed: &[u8; 32],
+                ) -> Result<(), Error> {
+                    crate::$module::crypto_kem_keypair_from_seed(
+                        as_array_mut(pk)?,
+                        as_array_mut(sk)?,
+                        seed,
+                    );
+                    Ok(())
+                }
+
+                fn public_key_from_secret_key(&self, pk: &mut [u8], sk: &[u8]) -> Result<(), Error> {
+                    crate::$module::crypto_kem_public_key_from_secret_key(
+                        as_array_mut(pk)?,
+                        as_array(sk)?,
+                    )
+                }
+
+                fn fingerprint(&self, pk: &[u8]) -> Result<Fingerprint, Error> {
+                    Ok(crate::$module::public_key_fingerprint(as_array(pk)?))
+                }
+
+                #[cfg(feature = "std")]
+                fn fingerprint_from_reader(
+                    &self,
+                    reader: &mut dyn std::io::Read,
+                ) -> Result<Fingerprint, Error> {
+                    crate::$module::public_key_fingerprint_from_reader(reader)
+                }
+
+                fn validate_public_key(&self, pk: &[u8]) -> Result<(), Error> {
+                    crate::$module::validate_public_key(pk)
+                }
+
+                fn validate_secret_key(&self, sk: &[u8], check_seed: bool) -> Result<(), Error> {
+                    crate::$module::validate_secret_key(sk, check_seed)
+                }
+
+                fn encapsulate(
+                    &self,
+                    ct: &mut [u8],
+                    ss: &mut [u8],
+                    pk: &[u8],
+                    mut rng: &mut dyn CryptoRngCore,
+                ) -> Result<(), Error> {
+                    crate::$module::crypto_kem_enc_slice(ct, ss, pk, &mut rng)
+                }
+
+                #[cfg(feature = "std")]
+                fn encapsulate_from_reader(
+                    &self,
+                    ct: &mut [u8],
+                    ss: &mut [u8],
+                    reader: &mut dyn std::io::Read,
+                    mut rng: &mut dyn CryptoRngCore,
+                ) -> Result<Fingerprint, Error> {
+                    crate::$module::crypto_kem_enc_from_reader(
+                        as_array_mut(ct)?,
+                        as_array_mut(ss)?,
+                        reader,
+                        &mut rng,
+                    )
+                }
+
+                fn decapsulate(
+                    &self,
+                    ss: &mut [u8],
+                    ct: &[u8],
+                    sk: &[u8],
+                ) -> Result<(), Error> {
+                    crate::$module::crypto_kem_dec_slice(ss, ct, sk)
+                }
+            }
+        )*
+    };
+}
+
+variants! {
+    Mceliece348864 => mceliece348864, Mceliece348864Kem;
+    Mceliece348864f => mceliece348864f, Mceliece348864fKem;
+    Mceliece348864pc => mceliece348864pc, Mceliece348864pcKem;
+    Mceliece348864pcf => mceliece348864pcf, Mceliece348864pcfKem;
+    Mceliece460896 => mceliece460896, Mceliece460896Kem;
+    Mceliece460896f => mceliece460896f, Mceliece460896fKem;
+    Mceliece460896pc => mceliece460896pc, Mceliece460896pcKem;
+    Mceliece460896pcf => mceliece460896pcf, Mceliece460896pcfKem;
+    Mceliece6688128 => mceliece6688128, Mceliece6688128Kem;
+    Mceliece6688128f => mceliece6688128f, Mceliece6688128fKem;
+    Mceliece6688128pc => mceliece6688128pc, Mceliece6688128pcKem;
+    Mceliece6688128pcf => mceliece6688128pcf, Mceliece6688128pcfKem;
+    Mceliece6960119 => mceliece6960119, Mceliece6960119Kem;
+    Mceliece6960119f => mceliece6960119f, Mceliece6960119fKem;
+    Mceliece6960119pc => mceliece6960119pc, Mceliece6960119pcKem;
+    Mceliece6960119pcf => mceliece6960119pcf, Mceliece6960119pcfKem;
+    Mceliece8192128 => mceliece8192128, Mceliece8192128Kem;
+    Mceliece8192128f => mceliece8192128f, Mceliece8192128fKem;
+    Mceliece8192128pc => mceliece8192128pc, Mceliece8192128pcKem;
+    Mceliece8192128pcf => mceliece8192128pcf, Mceliece8192128pcfKem;
+}
+
+/// Parses the name of a `pc` or `pcf` variant. Names without `pc` denote variants with
+/// plaintext confirmation in round 3, but variants without it in round 4. They are rejected
+/// with `Error::AmbiguousVariant` and must be parsed by `Variant::from_round3_name` or
+/// `Variant::from_round4_name`.
+impl FromStr for Variant {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let variant = Variant::from_round4_name(s)?;
+        if !variant.name().contains("pc") {
+            return Err(Error::AmbiguousVariant);
+        }
+        Ok(variant)
+    }
+}
+
+impl Variant {
+    /// Parses the name of a NIST round-4 variant. Names without `pc` denote the variants
+    /// without plaintext confirmation, e.g. `mceliece348864` maps to `Variant::Mceliece348864`.
+    pub fn from_round4_name(name: &str) -> Result<Variant, Error> {
+        Variant::ALL
+            .iter()
+            .find(|v| v.name() == name)
+            .copied()
+            .ok_or(Error::UnknownVariant)
+    }
+
+    /// Parses the name of a NIST round-3 variant into the variant with the same wire format.
+    ///
+    /// Round 3 applies plaintext confirmation to every variant, hence e.g. `mceliece348864f`
+    /// maps to `Variant::Mceliece348864pcf`. Keys, ciphertexts and shared secrets are
+    /// identical to those of the round-3 reference implementation.
+    pub fn from_round3_name(name: &str) -> Result<Variant, Error> {
+        let (params, suffix) = match name.strip_suffix('f') {
+            Some(params) => (params, "f"),
+            None => (name, ""),
+        };
+
+        Variant::ALL
+            .iter()
+            .find(|v| {
+                v.name()
+                    .strip_suffix(suffix)
+                    .and_then(|n| n.strip_suffix("pc"))
+                    == Some(params)
+            })
+            .copied()
+            .ok_or(Error::UnknownVariant)
+    }
+}
+
+impl fmt::Display for Variant {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AesState, RNGState};
+
+    #[test]
+    fn test_variant_from_str() {
+        for variant in Variant::ALL {
+            assert_eq!(Variant::from_round4_name(variant.name()), Ok(variant));
+            assert_eq!(variant.to_string(), variant.name());
+            assert_eq!(variant.kem().variant(), variant);
+        }
+
+        assert_eq!(
+            "mceliece460896pcf".parse::<Variant>(),
+            Ok(Variant::Mceliece460896pcf)
+        );
+        // names without `pc` denote different variants in rounds 3 and 4
+        for name in ["mceliece348864", "mceliece6960119f"] {
+            assert_eq!(name.parse::<Variant>(), Err(Error::AmbiguousVariant));
+        }
+        assert_eq!(
+            Variant::from_round4_name("mceliece348864"),
+            Ok(Variant::Mceliece348864)
+        );
+        assert_eq!(
+            "mceliece1234".parse::<Variant>(),
+            Err(Error::UnknownVariant)
+        );
+        assert_eq!(
+            Variant::from_round4_name("mceliece1234"),
+            Err(Error::UnknownVariant)
+        );
+    }
+
+    #[test]
+    fn test_variant_from_round3_name() {
+        assert_eq!(
+            Variant::from_round3_name("mceliece348864"),
+            Ok(Variant::Mceliece348864pc)
+        );
+        assert_eq!(
+            Variant::from_round3_name("mceliece6960119f"),
+            Ok(Variant::Mceliece6960119pcf)
+        );
+        for name in ["mceliece348864pc", "mceliece460896pcf", "mceliece1234"] {
+            assert_eq!(Variant::from_round3_name(name), Err(Error::UnknownVariant));
+        }
+    }
+
+    #[test]
+    fn test_variant_sizes() {
+        use crate::mceliece6960119f;
+
+        let v = Variant::Mceliece6960119f;
+        assert_eq!(
+            v.public_key_bytes(),
+            mceliece6960119f::CRYPTO_PUBLICKEYBYTES
+        );
+        assert_eq!(
+            v.secret_key_bytes(),
+            mceliece6960119f::CRYPTO_SECRETKEYBYTES
+        );
+        assert_eq!(
+            v.ciphertext_bytes(),
+            mceliece6960119f::CRYPTO_CIPHERTEXTBYTES
+        );
+        assert_eq!(v.shared_secret_bytes(), mceliece6960119f::CRYPTO_BYTES);
+
+        let v = Variant::Mceliece8192128;
+        assert_eq!(v.public_key_bytes(), 1357824);
+        assert_eq!(v.secret_key_bytes(), 14120);
+        assert_eq!(v.ciphertext_bytes(), 208);
+        assert_eq!(Variant::Mceliece8192128pc.ciphertext_bytes(), 240);
+        assert_eq!(v.shared_secret_bytes(), 32);
+    }
+
+    #[test]
+    fn test_kem_roundtrip() -> Result<(), Error> {
+        let variant = Variant::Mceliece348864;
+        let kem = variant.kem();
+        let mut rng = AesState::new();
+        rng.randombytes_init([42u8; 48]);
+
+        let mut pk = vec![0u8; variant.public_key_bytes()];
+        let mut sk = vec![0u8; variant.secret_key_bytes()];
+        let mut ct = vec![0u8; variant.ciphertext_bytes()];
+        let mut ss_alice = vec![0u8; variant.shared_secret_bytes()];
+        let mut ss_bob = vec![0u8; variant.shared_secret_bytes()];
+
+        kem.keypair(&mut pk, &mut sk, &mut rng)?;
+        kem.encapsulate(&mut ct, &mut ss_bob, &pk, &mut rng)?;
+        kem.decapsulate(&mut ss_alice, &ct, &sk)?;
+        assert_eq!(ss_alice, ss_bob);
+
+        assert_eq!(
+            kem.decapsulate(&mut ss_alice, &ct, &pk),
+            Err(Error::InvalidLength {
+                expected: variant.secret_key_bytes(),
+                actual: variant.public_key_bytes(),
+            })
+        );
+
+        let mut pk_from_seed = vec![0u8; variant.public_key_bytes()];
+        let mut sk_from_seed = vec![0u8; variant.secret_key_bytes()];
+        let seed = <&[u8; 32]>::try_from(&sk[..32]).unwrap();
+        kem.keypair_from_seed(&mut pk_from_seed, &mut sk_from_seed, seed)?;
+        assert_eq!(pk_from_seed, pk);
+        assert_eq!(sk_from_seed, sk);
+
+        let mut pk_from_sk = vec![0u8; variant.public_key_bytes()];
+        kem.public_key_from_secret_key(&mut pk_from_sk, &sk)?;
+        assert_eq!(pk_from_sk, pk);
+
+        kem.validate_public_key(&pk)?;
+
+        let fingerprint = kem.fingerprint(&pk)?;
+        #[cfg(feature = "std")]
+        assert_eq!(
+            kem.fingerprint_from_reader(&mut &pk[..]).unwrap(),
+            fingerprint
+        );
+        #[cfg(feature = "std")]
+        {
+            let mut ct_streamed = vec![0u8; variant.ciphertext_bytes()];
+            let mut ss_streamed = vec![0u8; variant.shared_secret_bytes()];
+            let streamed_fingerprint = kem.encapsulate_from_reader(
+                &mut ct_streamed,
+                &mut ss_streamed,
+                &mut &pk[..],
+                &mut rng,
+            )?;
+            assert_eq!(streamed_fingerprint, fingerprint);
+            kem.decapsulate(&mut ss_alice, &ct_streamed, &sk)?;
+            assert_eq!(ss_alice, ss_streamed);
+        }
+        for other in Variant::ALL {
+            if other != variant && other.public_key_bytes() == pk.len() {
+                assert_ne!(other.kem().fingerprint(&pk)?, fingerprint);
+            }
+        }
+        kem.validate_secret_key(&sk, true)?;
+
+        Ok(())
+    }
+}
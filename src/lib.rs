@@ -1,59 +1,267 @@
 //! This is a pure-rust safe-rust implementation of the Classic McEliece post-quantum scheme.
 //!
-//! An example is provided to illustrate the API. Be aware that this documentation is generated
-//! for one specific variant (among ten). Thus the array lengths will be different if you specify
-//! a different variant via feature flags.
-
-mod api;
-mod benes;
-mod bm;
-mod controlbits;
+//! All twenty variants are compiled into the crate. Each of them lives in its own module
+//! (e.g. [`mceliece460896f`]) which provides the KEM functions and the array lengths
+//! of this particular parameter set. An example is provided to illustrate the API.
+//!
+//! The variants are named as in the NIST round-4 submission. The `pc` and `pcf` variants
+//! (e.g. [`mceliece460896pcf`]) use plaintext confirmation: their ciphertexts carry an additional
+//! 32-byte hash of the error vector. The other variants transmit the syndrome only.
+//! In NIST round 3, every variant used plaintext confirmation, hence the round-3 wire format
+//! is provided by the `pc` variants. [`Variant::from_round3_name`] maps round-3 names accordingly.
+//! The `pc` and `pcf` variants reproduce the known-answer tests of the round-3 reference
+//! implementation. The other variants have not yet been checked against the known-answer tests
+//! of the round-4 reference implementation.
+//!
+//! If the variant is only known at runtime, [`Variant`] can be parsed from its name
+//! (names without `pc` via [`Variant::from_round4_name`] or [`Variant::from_round3_name`])
+//! and provides the sizes of this parameter set as well as a [`Kem`] operating on byte slices.
+//!
+//! Public keys take up to 1.3 MB. Hence, the typed API stores keys on the heap and
+//! large internal buffers are heap-allocated as well. No public operation uses more
+//! than [`MAX_STACK_USAGE`] bytes of stack.
+//!
+//! Randomness is drawn from any `rand_core::CryptoRng + RngCore`. Use `OsRng` for key generation.
+//! [`AesState`] is the deterministic generator of the NIST known-answer tests and must be seeded.
+//!
+//! The crate supports `no_std` environments. The default feature `std` adds
+//! `std::error::Error` for [`Error`] and enables `getrandom`, which provides `OsRng`.
+//! Disabling it requires the `alloc` feature for the typed API and heap-allocated buffers.
+//! Without `alloc`, only the `crypto_kem_*` functions and [`Kem`] are available
+//! and the internal buffers are placed on the stack.
+//!
+//! [`Gf12`] and [`Gf13`] are the elements of the binary fields underlying the Goppa codes,
+//! with the constant-time arithmetic of the implementation behind the usual operators.
+//! Every variant module also provides `Poly` and `ExtFieldElem` for polynomials over GF(2^m)
+//! and the extension field GF((2^m)^t) of this variant, from which Goppa polynomials are derived.
+//!
+//! The `hazmat` feature adds a `hazmat` module to every variant (e.g. `mceliece348864::hazmat`),
+//! which exposes Niederreiter encryption and the decoder of the Goppa code for research purposes.
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 mod crypto_hash;
-mod decrypt;
-mod encrypt;
-mod gf;
+mod error;
+mod field;
+mod fingerprint;
 mod int32_sort;
-mod operations;
-mod params;
-mod pk_gen;
+mod kem;
 mod randombytes;
-mod root;
-mod sk_gen;
-mod synd;
 mod transpose;
 mod uint64_sort;
-mod util;
 
-pub use api::{
-    CRYPTO_BYTES, CRYPTO_CIPHERTEXTBYTES, CRYPTO_PRIMITIVE, CRYPTO_PUBLICKEYBYTES,
-    CRYPTO_SECRETKEYBYTES,
-};
-pub use operations::{crypto_kem_dec, crypto_kem_enc, crypto_kem_keypair};
-pub use randombytes::{AesState, RNGState};
+pub mod mceliece348864;
+pub mod mceliece348864f;
+pub mod mceliece348864pc;
+pub mod mceliece348864pcf;
+pub mod mceliece460896;
+pub mod mceliece460896f;
+pub mod mceliece460896pc;
+pub mod mceliece460896pcf;
+pub mod mceliece6688128;
+pub mod mceliece6688128f;
+pub mod mceliece6688128pc;
+pub mod mceliece6688128pcf;
+pub mod mceliece6960119;
+pub mod mceliece6960119f;
+pub mod mceliece6960119pc;
+pub mod mceliece6960119pcf;
+pub mod mceliece8192128;
+pub mod mceliece8192128f;
+pub mod mceliece8192128pc;
+pub mod mceliece8192128pcf;
+
+pub use error::Error;
+pub use field::{Gf12, Gf13};
+pub use fingerprint::{Fingerprint, FINGERPRINT_BYTES};
+pub use kem::{Kem, Variant};
+pub use randombytes::{AesSeed, AesState, RNGState, RNGStateAdapter};
+
+pub use rand_core;
+#[cfg(feature = "getrandom")]
+pub use rand_core::OsRng;
+
+/// Upper bound for the stack size (in bytes) required by the KEM operations of any variant
+/// if the `alloc` feature is enabled. Keys passed to the `crypto_kem_*` functions are not included,
+/// they are placed wherever the caller allocates them.
+pub const MAX_STACK_USAGE: usize = 128 * 1024;
 
 mod macros {
     /// This macro(A, B, C, T) allows to get “&A[B..B+C]” of type “&[T]” as type “&[T; C]”.
     /// The default type T is u8 and “mut A” instead of “A” returns a mutable reference.
+    /// The conversion cannot fail, because the range has length C (out-of-bounds ranges panic).
     macro_rules! sub {
         ($var:expr, $offset:expr, $len:expr) => {{
-            use std::convert::TryFrom;
-            <&[u8; $len]>::try_from(&$var[$offset..($offset + $len)])?
+            use core::convert::TryFrom;
+            <&[u8; $len]>::try_from(&$var[$offset..($offset + $len)]).unwrap()
         }};
         (mut $var:expr, $offset:expr, $len:expr) => {{
-            use std::convert::TryFrom;
-            <&mut [u8; $len]>::try_from(&mut $var[$offset..($offset + $len)])?
+            use core::convert::TryFrom;
+            <&mut [u8; $len]>::try_from(&mut $var[$offset..($offset + $len)]).unwrap()
         }};
         ($var:expr, $offset:expr, $len:expr, $t:ty) => {{
-            use std::convert::TryFrom;
-            <&[$t; $len]>::try_from(&$var[$offset..($offset + $len)])?
+            use core::convert::TryFrom;
+            <&[$t; $len]>::try_from(&$var[$offset..($offset + $len)]).unwrap()
         }};
         (mut $var:expr, $offset:expr, $len:expr, $t:ty) => {{
-            use std::convert::TryFrom;
-            <&mut [$t; $len]>::try_from(&mut $var[$offset..($offset + $len)])?
+            use core::convert::TryFrom;
+            <&mut [$t; $len]>::try_from(&mut $var[$offset..($offset + $len)]).unwrap()
         }};
     }
 
+    /// This macro overwrites the given buffers with zeros, if the `zeroize` feature is enabled.
+    /// Otherwise it only borrows them mutably, which has no effect. Use it to wipe secret data before returning.
+    macro_rules! zeroize {
+        ($($buf:expr),+ $(,)?) => {
+            #[cfg(feature = "zeroize")]
+            {
+                use zeroize::Zeroize;
+                $($buf.zeroize();)+
+            }
+            #[cfg(not(feature = "zeroize"))]
+            {
+                $(let _ = &mut $buf;)+
+            }
+            #[cfg(test)]
+            {
+                $(crate::macros::log_wipe(stringify!($buf));)+
+            }
+        };
+    }
+
+    #[cfg(test)]
+    std::thread_local! {
+        static WIPED: core::cell::RefCell<std::vec::Vec<&'static str>> =
+            const { core::cell::RefCell::new(std::vec::Vec::new()) };
+    }
+
+    /// Records the name of a buffer passed to `zeroize!`, such that tests can check which buffers are wiped
+    #[cfg(test)]
+    pub(crate) fn log_wipe(name: &'static str) {
+        WIPED.with(|wiped| wiped.borrow_mut().push(name));
+    }
+
+    /// Returns and clears the names of the buffers passed to `zeroize!` by the current thread
+    #[cfg(test)]
+    pub(crate) fn take_wiped() -> std::vec::Vec<&'static str> {
+        WIPED.with(|wiped| wiped.take())
+    }
+
+    /// This macro declares the modules implementing the KEM inside a variant module.
+    /// The variant module must provide the constants `GFBITS`, `SYS_N`, `SYS_T`, `SEMI_SYSTEMATIC`,
+    /// `PLAINTEXT_CONFIRMATION` and `FIELD_POLY_TERMS` as well as the `CRYPTO_*` API constants.
+    /// Every variant thus compiles its own copy of the implementation with its own array lengths.
+    ///
+    /// The argument is the name of the variant module. It defines the test-only macro
+    /// `variant_tests!`, which expands the given test items only in the listed variant modules:
+    ///
+    /// ```ignore
+    /// variant_tests! {
+    ///     [mceliece348864, mceliece6960119]
+    ///
+    ///     #[test]
+    ///     fn test_specific_to_these_variants() {}
+    /// }
+    /// ```
+    macro_rules! kem_modules {
+        ($variant:ident) => {
+            crate::macros::kem_modules!(@ $variant $);
+        };
+        (@ $variant:ident $d:tt) => {
+            #[cfg(test)]
+            macro_rules! variant_tests {
+                ([$d($d names:ident),+ $d(,)?] $d($d item:item)*) => {
+                    variant_tests!(@find [$d($d names)+] $d($d item)*);
+                };
+                (@find [$variant $d($d rest:ident)*] $d($d item:item)*) => {
+                    $d($d item)*
+                };
+                (@find [$d other:ident $d($d rest:ident)*] $d($d item:item)*) => {
+                    variant_tests!(@find [$d($d rest)*] $d($d item)*);
+                };
+                (@find [] $d($d item:item)*) => {};
+            }
+
+            #[allow(clippy::duplicate_mod)]
+            #[path = "benes.rs"]
+            mod benes;
+            #[allow(clippy::duplicate_mod)]
+            #[path = "bm.rs"]
+            mod bm;
+            #[allow(clippy::duplicate_mod)]
+            #[path = "controlbits.rs"]
+            mod controlbits;
+            #[allow(clippy::duplicate_mod)]
+            #[path = "decrypt.rs"]
+            mod decrypt;
+            #[allow(clippy::duplicate_mod)]
+            #[path = "encrypt.rs"]
+            mod encrypt;
+            #[allow(clippy::duplicate_mod)]
+            #[path = "gf.rs"]
+            pub(crate) mod gf;
+            #[cfg(feature = "hazmat")]
+            #[allow(clippy::duplicate_mod)]
+            #[path = "hazmat.rs"]
+            pub mod hazmat;
+            #[allow(clippy::duplicate_mod)]
+            #[path = "operations.rs"]
+            mod operations;
+            #[allow(clippy::duplicate_mod)]
+            #[path = "params.rs"]
+            mod params;
+            #[allow(clippy::duplicate_mod)]
+            #[path = "pk_gen.rs"]
+            mod pk_gen;
+            #[allow(clippy::duplicate_mod)]
+            #[path = "poly.rs"]
+            mod poly;
+            #[allow(clippy::duplicate_mod)]
+            #[path = "root.rs"]
+            mod root;
+            #[allow(clippy::duplicate_mod)]
+            #[path = "sk_gen.rs"]
+            mod sk_gen;
+            #[allow(clippy::duplicate_mod)]
+            #[path = "synd.rs"]
+            mod synd;
+            #[allow(clippy::duplicate_mod)]
+            #[path = "types.rs"]
+            mod types;
+            #[allow(clippy::duplicate_mod)]
+            #[path = "util.rs"]
+            mod util;
+
+            pub use operations::{
+                crypto_kem_dec, crypto_kem_dec_slice, crypto_kem_enc, crypto_kem_enc_derand,
+                crypto_kem_enc_slice, crypto_kem_enc_with_error_vector, crypto_kem_keypair,
+                crypto_kem_keypair_from_seed, crypto_kem_keypair_slice,
+                crypto_kem_public_key_from_secret_key, public_key_fingerprint, validate_public_key,
+                validate_secret_key,
+            };
+            #[cfg(feature = "std")]
+            pub use operations::{
+                crypto_kem_enc_from_reader, encapsulate_from_reader,
+                public_key_fingerprint_from_reader,
+            };
+            #[cfg(feature = "alloc")]
+            pub use operations::{
+                decapsulate, encapsulate, encapsulate_derand, expand_secret_key, keypair,
+                public_key_from_secret_key,
+            };
+            pub use poly::{ExtFieldElem, Poly};
+            pub use types::{Ciphertext, SharedSecret};
+            #[cfg(feature = "alloc")]
+            pub use types::{PublicKey, SecretKey};
+        };
+    }
+
+    pub(crate) use kem_modules;
     pub(crate) use sub;
+    pub(crate) use zeroize;
 }
 
 #[cfg(test)]
@@ -123,16 +331,20 @@ impl TestData {
         TestData { data: bytes }
     }
 
+    /// Strips the suffix `f`, `pc` or `pcf` from the variant name, e.g. `mceliece348864pcf` becomes
+    /// `mceliece348864`. The test data of the base variant applies to all four.
+    fn base_variant(variant: &str) -> &str {
+        variant
+            .trim_end_matches("pcf")
+            .trim_end_matches("pc")
+            .trim_end_matches('f')
+    }
+
     impl_parser_per_type!(u8vec, 8, u8);
     impl_parser_per_type!(u16vec, 16, u16);
     impl_parser_per_type!(u32vec, 32, u32);
     impl_parser_per_type!(u64vec, 64, u64);
     //impl_parser_per_type!(i8vec, 8, i8);
-    #[cfg(any(
-        feature = "mceliece348864",
-        feature = "mceliece6960119",
-        feature = "mceliece8192128f"
-    ))]
     impl_parser_per_type!(i16vec, 16, i16);
     //impl_parser_per_type!(i32vec, 32, i32);
     //impl_parser_per_type!(i64vec, 64, i64);
@@ -142,6 +354,53 @@ impl TestData {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_zeroize_macro() {
+        let mut bytes = [0xABu8; 16];
+        let mut matrix = [[0x1234u16; 4]; 3];
+        let mut boxed = Box::new([0xCDCDu64; 8]);
+
+        macros::zeroize!(bytes, matrix, boxed);
+
+        if cfg!(feature = "zeroize") {
+            assert_eq!(bytes, [0u8; 16]);
+            assert_eq!(matrix, [[0u16; 4]; 3]);
+            assert_eq!(*boxed, [0u64; 8]);
+        } else {
+            assert_eq!(bytes, [0xABu8; 16]);
+            assert_eq!(matrix, [[0x1234u16; 4]; 3]);
+            assert_eq!(*boxed, [0xCDCDu64; 8]);
+        }
+    }
+
+    #[test]
+    fn test_zeroize_log() {
+        let mut seed = [1u8; 32];
+        let mut e = [2u8; 8];
+
+        macros::take_wiped();
+        macros::zeroize!(seed, e);
+        assert_eq!(macros::take_wiped(), ["seed", "e"]);
+        assert!(macros::take_wiped().is_empty());
+    }
+
+    #[test]
+    fn test_base_variant() {
+        for variant in kem::Variant::ALL {
+            let base = TestData::base_variant(variant.name());
+            assert!(variant.name().starts_with(base));
+            assert!(base.ends_with(|c: char| c.is_ascii_digit()));
+        }
+        for name in [
+            "mceliece6960119",
+            "mceliece6960119f",
+            "mceliece6960119pc",
+            "mceliece6960119pcf",
+        ] {
+            assert_eq!(TestData::base_variant(name), "mceliece6960119");
+        }
+    }
+
     #[test]
     fn testdata_sanity_check() {
         assert_eq!(
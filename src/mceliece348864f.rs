@@ -0,0 +1,26 @@
+//! Classic McEliece variant `mceliece348864f`
+//!
+//! The parameter set uses the field GF(2^12), a code length of n = 3488 and corrects t = 64 errors.
+//!
+//! The `f` suffix denotes faster key generation using the semi-systematic form.
+
+/// Name of the variant
+pub const CRYPTO_PRIMITIVE: &str = "mceliece348864f";
+/// The number of bytes required to store the public key
+pub const CRYPTO_PUBLICKEYBYTES: usize = 261120;
+/// The number of bytes required to store the secret key
+pub const CRYPTO_SECRETKEYBYTES: usize = 6492;
+/// The number of bytes required to store the ciphertext resulting from the encryption
+pub const CRYPTO_CIPHERTEXTBYTES: usize = 96;
+/// The number of bytes required to store the shared secret negotiated between both parties
+pub const CRYPTO_BYTES: usize = 32;
+
+const GFBITS: usize = 12;
+const SYS_N: usize = 3488;
+const SYS_T: usize = 64;
+const SEMI_SYSTEMATIC: bool = true;
+const PLAINTEXT_CONFIRMATION: bool = false;
+/// Non-leading terms (degree, coefficient) of `x^64 + x^3 + x + 2`, which defines GF((2^m)^t)
+const FIELD_POLY_TERMS: &[(usize, u16)] = &[(3, 1), (1, 1), (0, 2)];
+
+crate::macros::kem_modules!(mceliece348864f);
@@ -0,0 +1,24 @@
+//! Classic McEliece variant `mceliece460896`
+//!
+//! The parameter set uses the field GF(2^13), a code length of n = 4608 and corrects t = 96 errors.
+
+/// Name of the variant
+pub const CRYPTO_PRIMITIVE: &str = "mceliece460896";
+/// The number of bytes required to store the public key
+pub const CRYPTO_PUBLICKEYBYTES: usize = 524160;
+/// The number of bytes required to store the secret key
+pub const CRYPTO_SECRETKEYBYTES: usize = 13608;
+/// The number of bytes required to store the ciphertext resulting from the encryption
+pub const CRYPTO_CIPHERTEXTBYTES: usize = 156;
+/// The number of bytes required to store the shared secret negotiated between both parties
+pub const CRYPTO_BYTES: usize = 32;
+
+const GFBITS: usize = 13;
+const SYS_N: usize = 4608;
+const SYS_T: usize = 96;
+const SEMI_SYSTEMATIC: bool = false;
+const PLAINTEXT_CONFIRMATION: bool = false;
+/// Non-leading terms (degree, coefficient) of `x^96 + x^10 + x^9 + x^6 + 1`, which defines GF((2^m)^t)
+const FIELD_POLY_TERMS: &[(usize, u16)] = &[(10, 1), (9, 1), (6, 1), (0, 1)];
+
+crate::macros::kem_modules!(mceliece460896);
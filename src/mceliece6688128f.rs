@@ -0,0 +1,26 @@
+//! Classic McEliece variant `mceliece6688128f`
+//!
+//! The parameter set uses the field GF(2^13), a code length of n = 6688 and corrects t = 128 errors.
+//!
+//! The `f` suffix denotes faster key generation using the semi-systematic form.
+
+/// Name of the variant
+pub const CRYPTO_PRIMITIVE: &str = "mceliece6688128f";
+/// The number of bytes required to store the public key
+pub const CRYPTO_PUBLICKEYBYTES: usize = 1044992;
+/// The number of bytes required to store the secret key
+pub const CRYPTO_SECRETKEYBYTES: usize = 13932;
+/// The number of bytes required to store the ciphertext resulting from the encryption
+pub const CRYPTO_CIPHERTEXTBYTES: usize = 208;
+/// The number of bytes required to store the shared secret negotiated between both parties
+pub const CRYPTO_BYTES: usize = 32;
+
+const GFBITS: usize = 13;
+const SYS_N: usize = 6688;
+const SYS_T: usize = 128;
+const SEMI_SYSTEMATIC: bool = true;
+const PLAINTEXT_CONFIRMATION: bool = false;
+/// Non-leading terms (degree, coefficient) of `x^128 + x^7 + x^2 + x + 1`, which defines GF((2^m)^t)
+const FIELD_POLY_TERMS: &[(usize, u16)] = &[(7, 1), (2, 1), (1, 1), (0, 1)];
+
+crate::macros::kem_modules!(mceliece6688128f);
@@ -0,0 +1,24 @@
+//! Classic McEliece variant `mceliece6960119`
+//!
+//! The parameter set uses the field GF(2^13), a code length of n = 6960 and corrects t = 119 errors.
+
+/// Name of the variant
+pub const CRYPTO_PRIMITIVE: &str = "mceliece6960119";
+/// The number of bytes required to store the public key
+pub const CRYPTO_PUBLICKEYBYTES: usize = 1047319;
+/// The number of bytes required to store the secret key
+pub const CRYPTO_SECRETKEYBYTES: usize = 13948;
+/// The number of bytes required to store the ciphertext resulting from the encryption
+pub const CRYPTO_CIPHERTEXTBYTES: usize = 194;
+/// The number of bytes required to store the shared secret negotiated between both parties
+pub const CRYPTO_BYTES: usize = 32;
+
+const GFBITS: usize = 13;
+const SYS_N: usize = 6960;
+const SYS_T: usize = 119;
+const SEMI_SYSTEMATIC: bool = false;
+const PLAINTEXT_CONFIRMATION: bool = false;
+/// Non-leading terms (degree, coefficient) of `x^119 + x^8 + 1`, which defines GF((2^m)^t)
+const FIELD_POLY_TERMS: &[(usize, u16)] = &[(8, 1), (0, 1)];
+
+crate::macros::kem_modules!(mceliece6960119);
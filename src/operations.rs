@@ -1,31 +1,52 @@
 //! KEM API
 
-use std::error;
-
-use crate::controlbits::controlbitsfrompermutation;
-#[cfg(any(feature = "mceliece6960119", feature = "mceliece6960119f"))]
-use crate::params::{PK_NCOLS, PK_NROWS, PK_ROW_BYTES};
-use crate::randombytes::RNGState;
-use crate::{
-    api::{CRYPTO_BYTES, CRYPTO_CIPHERTEXTBYTES, CRYPTO_PUBLICKEYBYTES, CRYPTO_SECRETKEYBYTES},
-    crypto_hash::shake256,
+#[cfg(feature = "std")]
+use super::encrypt::syndrome_row;
+#[cfg(feature = "alloc")]
+use super::types::{Ciphertext, PublicKey, SecretKey, SharedSecret};
+use super::{
+    benes::support_gen,
+    controlbits::controlbitsfrompermutation,
     decrypt::decrypt,
-    encrypt::encrypt,
-    macros::sub,
-    params::{COND_BYTES, GFBITS, IRR_BYTES, SYND_BYTES, SYS_N, SYS_T},
-    pk_gen::pk_gen,
-    sk_gen::genpoly_gen,
-    util::{load4, load_gf, store8, store_gf},
+    encrypt::{encrypt, gen_e},
+    gf::Gf,
+    params::{
+        COND_BYTES, GFBITS, IRR_BYTES, PK_NCOLS, PK_NROWS, PK_ROW_BYTES, PLAINTEXT_CONFIRMATION,
+        SEMI_SYSTEMATIC, SYND_BYTES, SYS_N, SYS_T,
+    },
+    pk_gen::{pk_gen, pk_gen_from_permutation, restore_columns},
+    sk_gen::{genpoly_gen, irreducibility_check},
+    util::{bitrev, large_array, load4, load8, load_gf, store8, store_gf, LargeArray},
+    CRYPTO_BYTES, CRYPTO_CIPHERTEXTBYTES, CRYPTO_PRIMITIVE, CRYPTO_PUBLICKEYBYTES,
+    CRYPTO_SECRETKEYBYTES,
+};
+use crate::{
+    crypto_hash::{shake256, Shake256Rng},
+    error::{as_array, as_array_mut, Error},
+    fingerprint::{Fingerprint, FingerprintHasher},
+    macros::{sub, zeroize},
+    uint64_sort::uint64_sort,
 };
+use rand_core::{CryptoRng, RngCore};
 
 /// This function determines (in a constant-time manner) whether the padding bits of `pk` are all zero.
-#[cfg(any(feature = "mceliece6960119", feature = "mceliece6960119f"))]
+/// Returns 0 if so and 0xFF otherwise. Public keys without padding bits (`PK_NCOLS % 8 == 0`) always yield 0.
 fn check_pk_padding(pk: &[u8; PK_NROWS * PK_ROW_BYTES]) -> u8 {
     let mut b = 0u8;
     for i in 0..PK_NROWS {
         b |= pk[i * PK_ROW_BYTES + PK_ROW_BYTES - 1];
     }
 
+    pk_padding_mask(b)
+}
+
+/// Turns the disjunction `b` of the last bytes of all public key rows into the result of `check_pk_padding`.
+#[allow(clippy::manual_is_multiple_of)]
+fn pk_padding_mask(mut b: u8) -> u8 {
+    if PK_NCOLS % 8 == 0 {
+        return 0;
+    }
+
     b >>= PK_NCOLS % 8;
     b = b.wrapping_sub(1);
     b >>= 7;
@@ -33,79 +54,150 @@ fn check_pk_padding(pk: &[u8; PK_NROWS * PK_ROW_BYTES]) -> u8 {
 }
 
 /// This function determines (in a constant-time manner) whether the padding bits of `c` are all zero.
-#[cfg(any(feature = "mceliece6960119", feature = "mceliece6960119f"))]
+/// Returns 0 if so and 0xFF otherwise. Ciphertexts without padding bits (`PK_NROWS % 8 == 0`) always yield 0.
+#[allow(clippy::manual_is_multiple_of)]
 fn check_c_padding(c: &[u8; SYND_BYTES]) -> u8 {
+    if PK_NROWS % 8 == 0 {
+        return 0;
+    }
+
+    // The padding bits are the top bits of the last syndrome byte. Round 4 only drops the
+    // plaintext confirmation behind the syndrome of the non-pc ciphertexts, so the check carries
+    // over unchanged.
     let mut b = c[SYND_BYTES - 1] >> (PK_NROWS % 8);
     b = b.wrapping_sub(1);
     b >>= 7;
     b.wrapping_sub(1)
 }
 
+/// Turns the mask returned by `check_pk_padding` or `check_c_padding` into the outcome of the KEM functions.
+/// The outputs are masked in constant time beforehand, branching is fine because the padding bits are public.
+fn padding_outcome(mask: u8) -> Result<(), Error> {
+    if mask == 0 {
+        Ok(())
+    } else {
+        Err(Error::InvalidPadding)
+    }
+}
+
 /// KEM Encapsulation.
 ///
 /// Given a public key `pk`, sample a shared key.
 /// This shared key is returned through parameter `key` whereas
 /// the ciphertext (meant to be used for decapsulation) is returned as `c`.
-#[cfg(not(any(feature = "mceliece6960119", feature = "mceliece6960119f")))]
+/// The ciphertext is the syndrome of the error vector `e`, followed by the confirmation hash
+/// `H(2, e)` for the `pc` variants. The shared key is `H(1, e, c)`.
+///
+/// If the padding bits of `pk` are non-zero, `Error::InvalidPadding` is returned
+/// and `c` and `key` are cleared. Padding bits only exist for `mceliece6960119` and `mceliece6960119f`.
 pub fn crypto_kem_enc(
     c: &mut [u8; CRYPTO_CIPHERTEXTBYTES],
     key: &mut [u8; CRYPTO_BYTES],
     pk: &[u8; CRYPTO_PUBLICKEYBYTES],
-    rng: &mut impl RNGState,
-) -> Result<(), Box<dyn error::Error>> {
+    rng: &mut (impl CryptoRng + RngCore),
+) -> Result<(), Error> {
     let mut two_e = [0u8; 1 + SYS_N / 8];
     two_e[0] = 2;
 
-    let mut one_ec = [0u8; 1 + SYS_N / 8 + (SYND_BYTES + 32)];
-    one_ec[0] = 1;
-
-    encrypt(c, pk, sub!(mut two_e, 1, SYS_N / 8), rng)?;
+    // `two_e` is wiped if `gen_e` fails as well
+    let result = gen_e(sub!(mut two_e, 1, SYS_N / 8), rng)
+        .and_then(|()| enc_from_error_vector(c, key, pk, &two_e));
 
-    shake256(&mut c[SYND_BYTES..SYND_BYTES + 32], &two_e)?;
+    zeroize!(two_e);
 
-    one_ec[1..1 + SYS_N / 8].copy_from_slice(&two_e[1..1 + SYS_N / 8]);
-    one_ec[1 + SYS_N / 8..1 + SYS_N / 8 + SYND_BYTES + 32].copy_from_slice(&c[0..SYND_BYTES + 32]);
+    result
+}
 
-    shake256(&mut key[0..32], &one_ec)?;
+/// Domain separation prefix for the expansion of the coins of `crypto_kem_enc_derand`
+const ENC_COINS_DOMAIN: &[u8] = b"classic-mceliece-rust encapsulation coins v1\0";
 
-    Ok(())
+/// KEM Encapsulation with caller-supplied randomness.
+///
+/// Behaves like `crypto_kem_enc`, but the random bytes consumed while sampling the error vector
+/// are SHAKE256 of a fixed domain separation prefix and the 32-byte `coins`. The outputs hence only
+/// depend on `pk` and `coins`, regardless of how many candidates the sampling discards.
+/// Meant for test vectors and debugging. Reusing `coins` for the same public key yields the same
+/// shared key, so they must be secret and uniformly random in any other use.
+pub fn crypto_kem_enc_derand(
+    c: &mut [u8; CRYPTO_CIPHERTEXTBYTES],
+    key: &mut [u8; CRYPTO_BYTES],
+    pk: &[u8; CRYPTO_PUBLICKEYBYTES],
+    coins: &[u8; 32],
+) -> Result<(), Error> {
+    crypto_kem_enc(c, key, pk, &mut Shake256Rng::new(ENC_COINS_DOMAIN, coins))
 }
 
-/// KEM Encapsulation.
+/// KEM Encapsulation of a caller-supplied error vector.
 ///
-/// Given a public key `pk`, sample a shared key.
-/// This shared key is returned through parameter `key` whereas
-/// the ciphertext (meant to be used for decapsulation) is returned as `c`.
-#[cfg(any(feature = "mceliece6960119", feature = "mceliece6960119f"))]
-pub fn crypto_kem_enc(
+/// This is the deterministic part of `crypto_kem_enc` once the error vector `e` of `SYS_N` bits
+/// has been sampled. If `e` does not have Hamming weight `SYS_T`, `Error::InvalidWeight` is returned
+/// and `c` and `key` are left untouched. Otherwise, the outcome is the same as for `crypto_kem_enc`.
+pub fn crypto_kem_enc_with_error_vector(
     c: &mut [u8; CRYPTO_CIPHERTEXTBYTES],
     key: &mut [u8; CRYPTO_BYTES],
     pk: &[u8; CRYPTO_PUBLICKEYBYTES],
-    rng: &mut impl RNGState,
-) -> Result<u8, Box<dyn error::Error>> {
+    e: &[u8; SYS_N / 8],
+) -> Result<(), Error> {
+    let weight: usize = e.iter().map(|b| b.count_ones() as usize).sum();
+    if weight != SYS_T {
+        return Err(Error::InvalidWeight {
+            expected: SYS_T,
+            actual: weight,
+        });
+    }
+
     let mut two_e = [0u8; 1 + SYS_N / 8];
     two_e[0] = 2;
+    two_e[1..].copy_from_slice(e);
 
-    let mut one_ec = [0u8; 1 + SYS_N / 8 + (SYND_BYTES + 32)];
-    one_ec[0] = 1;
+    let result = enc_from_error_vector(c, key, pk, &two_e);
 
+    zeroize!(two_e);
+
+    result
+}
+
+/// Encapsulation of the error vector `e` given as `two_e` = (2, e)
+fn enc_from_error_vector(
+    c: &mut [u8; CRYPTO_CIPHERTEXTBYTES],
+    key: &mut [u8; CRYPTO_BYTES],
+    pk: &[u8; CRYPTO_PUBLICKEYBYTES],
+    two_e: &[u8; 1 + SYS_N / 8],
+) -> Result<(), Error> {
     let padding_ok = check_pk_padding(pk);
 
-    encrypt(c, pk, sub!(mut two_e, 1, SYS_N / 8), rng)?;
+    encrypt(c, pk, sub!(two_e, 1, SYS_N / 8));
 
-    shake256(&mut c[SYND_BYTES..(SYND_BYTES + 32)], &two_e)?;
+    enc_finish(c, key, two_e, padding_ok)
+}
+
+/// Second part of the encapsulation, once the syndrome is stored in `c`.
+/// Computes the confirmation hash (`pc` variants) and the shared key `key` from `two_e` = (2, e)
+/// and clears both outputs unless `padding_ok` is 0.
+#[allow(clippy::needless_range_loop)]
+fn enc_finish(
+    c: &mut [u8; CRYPTO_CIPHERTEXTBYTES],
+    key: &mut [u8; CRYPTO_BYTES],
+    two_e: &[u8; 1 + SYS_N / 8],
+    padding_ok: u8,
+) -> Result<(), Error> {
+    let mut one_ec = [0u8; 1 + SYS_N / 8 + CRYPTO_CIPHERTEXTBYTES];
+    one_ec[0] = 1;
+
+    if PLAINTEXT_CONFIRMATION {
+        shake256(&mut c[SYND_BYTES..], two_e);
+    }
 
     one_ec[1..1 + (SYS_N / 8)].copy_from_slice(&two_e[1..(SYS_N / 8) + 1]);
-    one_ec[1 + (SYS_N / 8)..1 + (SYS_N / 8) + SYND_BYTES + 32]
-        .copy_from_slice(&c[0..SYND_BYTES + 32]);
+    one_ec[1 + (SYS_N / 8)..].copy_from_slice(&c[..]);
 
-    shake256(&mut key[0..32], &one_ec)?;
+    shake256(&mut key[0..32], &one_ec);
 
     // clear outputs (set to all 0's) if padding bits are not all zero
 
     let mask = padding_ok ^ 0xFF;
 
-    for i in 0..SYND_BYTES + 32 {
+    for i in 0..CRYPTO_CIPHERTEXTBYTES {
         c[i] &= mask;
     }
 
@@ -113,72 +205,136 @@ pub fn crypto_kem_enc(
         key[i] &= mask;
     }
 
-    Ok(padding_ok)
+    zeroize!(one_ec);
+
+    padding_outcome(padding_ok)
 }
 
-/// KEM Decapsulation.
-///
-/// Given a secret key `sk` and a ciphertext `c`,
-/// determine the shared text `key` negotiated by both parties.
-#[cfg(not(any(feature = "mceliece6960119", feature = "mceliece6960119f")))]
-pub fn crypto_kem_dec(
-    key: &mut [u8; CRYPTO_BYTES],
-    c: &[u8; CRYPTO_CIPHERTEXTBYTES],
-    sk: &[u8; CRYPTO_SECRETKEYBYTES],
-) -> Result<u8, Box<dyn error::Error>> {
-    let mut conf = [0u8; 32];
-    let mut two_e = [0u8; 1 + SYS_N / 8];
-    two_e[0] = 2;
+/// Fills `buf` from `reader`, retrying interrupted reads.
+/// Returns the number of bytes read, which is less than `buf.len()` only at the end of the input.
+#[cfg(feature = "std")]
+fn read_full(reader: &mut impl std::io::Read, buf: &mut [u8]) -> Result<usize, Error> {
+    let mut filled = 0;
+
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..]) {
+            Ok(0) => break,
+            Ok(n) => filled += n,
+            Err(err) if err.kind() == std::io::ErrorKind::Interrupted => {}
+            Err(err) => return Err(err.into()),
+        }
+    }
 
-    let mut preimage = [0u8; 1 + SYS_N / 8 + (SYND_BYTES + 32)];
+    Ok(filled)
+}
 
-    let ret_decrypt: u8 = decrypt(
-        sub!(mut two_e, 1, SYS_N / 8),
-        sub!(sk, 40, IRR_BYTES + COND_BYTES),
-        sub!(c, 0, SYND_BYTES),
-    )?;
+/// Computes the syndrome `s` of `e` for the public key read from `reader` one row at a time.
+/// Every row is passed to `hasher`. Returns the padding mask like `check_pk_padding`.
+#[cfg(feature = "std")]
+fn syndrome_from_reader(
+    s: &mut [u8; SYND_BYTES],
+    reader: &mut impl std::io::Read,
+    e: &[u8; SYS_N / 8],
+    hasher: &mut FingerprintHasher,
+) -> Result<u8, Error> {
+    let mut row = [0u8; PK_ROW_BYTES];
+    let mut padding = 0u8;
 
-    shake256(&mut conf[0..32], &two_e)?;
+    s.fill(0);
 
-    let mut ret_confirm: u8 = 0;
-    for i in 0..32 {
-        ret_confirm |= conf[i] ^ c[SYND_BYTES + i];
+    for i in 0..PK_NROWS {
+        let len = read_full(reader, &mut row)?;
+        if len < PK_ROW_BYTES {
+            return Err(Error::InvalidLength {
+                expected: CRYPTO_PUBLICKEYBYTES,
+                actual: i * PK_ROW_BYTES + len,
+            });
+        }
+
+        hasher.update(&row);
+        padding |= row[PK_ROW_BYTES - 1];
+        syndrome_row(s, i, &row, e);
     }
 
-    let mut m = (ret_decrypt | ret_confirm) as u16;
-    m = m.wrapping_sub(1);
-    m >>= 8;
+    Ok(pk_padding_mask(padding))
+}
 
-    preimage[0] = (m & 1) as u8;
+/// KEM Encapsulation with a public key read from `reader`.
+///
+/// The public key is consumed one row of `PK_ROW_BYTES` bytes at a time, hence it is never
+/// stored as a whole. Exactly `CRYPTO_PUBLICKEYBYTES` bytes are read. Returns the fingerprint of
+/// the public key, see `public_key_fingerprint`, which is computed along the way.
+/// If `reader` ends early, `Error::InvalidLength` reports the number of bytes read.
+/// Read errors are returned as `Error::Io`. Otherwise, the outcome is the same as for `crypto_kem_enc`.
+#[cfg(feature = "std")]
+pub fn crypto_kem_enc_from_reader(
+    c: &mut [u8; CRYPTO_CIPHERTEXTBYTES],
+    key: &mut [u8; CRYPTO_BYTES],
+    mut reader: impl std::io::Read,
+    rng: &mut (impl CryptoRng + RngCore),
+) -> Result<Fingerprint, Error> {
+    let mut two_e = [0u8; 1 + SYS_N / 8];
+    two_e[0] = 2;
 
-    let s = &sk[40 + IRR_BYTES + COND_BYTES..];
+    let mut hasher = FingerprintHasher::new(CRYPTO_PRIMITIVE);
 
-    for i in 0..SYS_N / 8 {
-        preimage[1 + i] = (!m as u8 & s[i]) | (m as u8 & two_e[1 + i]);
-    }
+    let result = gen_e(sub!(mut two_e, 1, SYS_N / 8), rng)
+        .and_then(|()| {
+            syndrome_from_reader(
+                sub!(mut c, 0, SYND_BYTES),
+                &mut reader,
+                sub!(two_e, 1, SYS_N / 8),
+                &mut hasher,
+            )
+        })
+        .and_then(|padding_ok| enc_finish(c, key, &two_e, padding_ok));
 
-    (&mut preimage[1 + (SYS_N / 8)..])[0..SYND_BYTES + 32].copy_from_slice(&c[0..SYND_BYTES + 32]);
+    zeroize!(two_e);
 
-    shake256(&mut key[0..32], &preimage)?;
+    if result.is_err() {
+        c.fill(0);
+        key.fill(0);
+    }
 
-    Ok(0)
+    result.map(|()| hasher.finalize())
 }
 
 /// KEM Decapsulation.
 ///
 /// Given a secret key `sk` and a ciphertext `c`,
 /// determine the shared text `key` negotiated by both parties.
-#[cfg(any(feature = "mceliece6960119", feature = "mceliece6960119f"))]
+/// If decoding fails or, for the `pc` variants, the confirmation hash does not match,
+/// `key` is derived as `H(0, s, c)` from the random string `s` of the secret key.
+///
+/// If the padding bits of `c` are non-zero, `Error::InvalidPadding` is returned
+/// and `key` is set to all 1's. Padding bits only exist for `mceliece6960119` and `mceliece6960119f`.
 pub fn crypto_kem_dec(
     key: &mut [u8; CRYPTO_BYTES],
     c: &[u8; CRYPTO_CIPHERTEXTBYTES],
     sk: &[u8; CRYPTO_SECRETKEYBYTES],
-) -> Result<u8, Box<dyn error::Error>> {
+) -> Result<(), Error> {
+    crypto_kem_dec_with_status(key, c, sk).map(|_| ())
+}
+
+/// KEM Decapsulation reporting implicit rejection (exported by the `hazmat` module).
+///
+/// Computes the same `key` as `crypto_kem_dec` and returns a mask, which is 0xFF if decoding and,
+/// for the `pc` variants, the confirmation hash succeeded, and 0 if `key` was derived from the random
+/// string `s` of the secret key instead. The mask is computed in constant time and should be consumed
+/// in constant time as well, e.g. for explicit key confirmation.
+///
+/// Hazmat: revealing the mask to an attacker turns the KEM into a decryption oracle.
+#[allow(clippy::needless_range_loop)]
+pub fn crypto_kem_dec_with_status(
+    key: &mut [u8; CRYPTO_BYTES],
+    c: &[u8; CRYPTO_CIPHERTEXTBYTES],
+    sk: &[u8; CRYPTO_SECRETKEYBYTES],
+) -> Result<u8, Error> {
     let mut conf = [0u8; 32];
     let mut two_e = [0u8; 1 + SYS_N / 8];
     two_e[0] = 2;
 
-    let mut preimage = [0u8; 1 + SYS_N / 8 + (SYND_BYTES + 32)];
+    let mut preimage = [0u8; 1 + SYS_N / 8 + CRYPTO_CIPHERTEXTBYTES];
 
     let padding_ok = check_c_padding(sub!(c, 0, SYND_BYTES));
 
@@ -186,13 +342,15 @@ pub fn crypto_kem_dec(
         sub!(mut two_e, 1, SYS_N / 8),
         sub!(sk, 40, IRR_BYTES + COND_BYTES),
         sub!(c, 0, SYND_BYTES),
-    )?;
-
-    shake256(&mut conf[0..32], &two_e)?;
+    );
 
     let mut ret_confirm: u8 = 0;
-    for i in 0..32 {
-        ret_confirm |= conf[i] ^ c[SYND_BYTES + i];
+    if PLAINTEXT_CONFIRMATION {
+        shake256(&mut conf[0..32], &two_e);
+
+        for (a, b) in conf.iter().zip(&c[SYND_BYTES..]) {
+            ret_confirm |= a ^ b;
+        }
     }
 
     let mut m = (ret_decrypt | ret_confirm) as u16;
@@ -207,9 +365,9 @@ pub fn crypto_kem_dec(
         preimage[1 + i] = (!m as u8 & s[i]) | (m as u8 & two_e[1 + i]);
     }
 
-    (&mut preimage[1 + (SYS_N / 8)..])[0..SYND_BYTES + 32].copy_from_slice(&c[0..SYND_BYTES + 32]);
+    preimage[1 + (SYS_N / 8)..].copy_from_slice(&c[..]);
 
-    shake256(&mut key[0..32], &preimage)?;
+    shake256(&mut key[0..32], &preimage);
 
     // clear outputs (set to all 1's) if padding bits are not all zero
 
@@ -219,7 +377,9 @@ pub fn crypto_kem_dec(
         key[i] |= mask;
     }
 
-    Ok(padding_ok)
+    zeroize!(conf, two_e, preimage);
+
+    padding_outcome(padding_ok).map(|()| m as u8)
 }
 
 /// KEM Keypair generation.
@@ -234,10 +394,35 @@ pub fn crypto_kem_dec(
 pub fn crypto_kem_keypair(
     pk: &mut [u8; CRYPTO_PUBLICKEYBYTES],
     sk: &mut [u8; CRYPTO_SECRETKEYBYTES],
-    rng: &mut impl RNGState,
-) -> Result<(), Box<dyn error::Error>> {
+    rng: &mut (impl CryptoRng + RngCore),
+) -> Result<(), Error> {
+    let mut seed = [0u8; 32];
+    let result = rng.try_fill_bytes(&mut seed);
+
+    if result.is_ok() {
+        crypto_kem_keypair_from_seed(pk, sk, &seed);
+    }
+
+    zeroize!(seed);
+
+    result.map_err(Error::from)
+}
+
+/// KEM Keypair generation from a caller-supplied seed.
+///
+/// This is the deterministic part of `crypto_kem_keypair`, which expands the 32-byte `delta`
+/// into the keypair using SHAKE256 as specified. `crypto_kem_keypair` draws `delta` from its RNG.
+/// If `delta` does not yield a valid key, the expansion is repeated with a new seed
+/// derived from it. The seed of the successful iteration is stored in the first 32 bytes of `sk`,
+/// hence expanding these bytes results in the same keypair.
+pub fn crypto_kem_keypair_from_seed(
+    pk: &mut [u8; CRYPTO_PUBLICKEYBYTES],
+    sk: &mut [u8; CRYPTO_SECRETKEYBYTES],
+    delta: &[u8; 32],
+) {
     let mut seed = [0u8; 33];
     seed[0] = 64;
+    seed[1..].copy_from_slice(delta);
 
     const S_BASE: usize = 32 + 8 + IRR_BYTES + COND_BYTES;
 
@@ -245,36 +430,20 @@ pub fn crypto_kem_keypair(
     const IRR_POLYS: usize = SYS_N / 8 + (1 << GFBITS) * 4;
     const PERM: usize = SYS_N / 8;
 
-    let mut r = [0u8; SEED + 32];
+    // these buffers take up to 80 KB, hence they live on the heap if possible
+    let mut r: LargeArray<u8, { SEED + 32 }> = large_array(0u8);
 
-    #[cfg(any(
-        feature = "mceliece348864f",
-        feature = "mceliece460896f",
-        feature = "mceliece6688128f",
-        feature = "mceliece6960119f",
-        feature = "mceliece8192128f"
-    ))]
     let mut pivots = 0u64;
-    #[cfg(any(
-        feature = "mceliece348864",
-        feature = "mceliece460896",
-        feature = "mceliece6688128",
-        feature = "mceliece6960119",
-        feature = "mceliece8192128"
-    ))]
-    let pivots: u64;
 
     let mut f = [0u16; SYS_T];
     let mut irr = [0u16; SYS_T];
 
-    let mut perm = [0u32; 1 << GFBITS];
-    let mut pi = [0i16; 1 << GFBITS];
-
-    rng.randombytes(&mut seed[1..])?;
+    let mut perm: LargeArray<u32, { 1 << GFBITS }> = large_array(0u32);
+    let mut pi: LargeArray<i16, { 1 << GFBITS }> = large_array(0i16);
 
     loop {
         // expanding and updating the seed
-        shake256(&mut r[..], &seed[0..33])?;
+        shake256(&mut r[..], &seed[0..33]);
 
         sk[..32].clone_from_slice(&seed[1..]);
         seed[1..].clone_from_slice(&r[r.len() - 32..]);
@@ -285,7 +454,7 @@ pub fn crypto_kem_keypair(
             f[i] = load_gf(sub!(chunk, 0, 2));
         }
 
-        if genpoly_gen(&mut irr, &mut f) != 0 {
+        if genpoly_gen(&mut irr, &f) != 0 {
             continue;
         }
 
@@ -299,41 +468,13 @@ pub fn crypto_kem_keypair(
             perm[i] = load4(sub!(chunk, 0, 4));
         }
 
-        #[cfg(any(
-            feature = "mceliece348864f",
-            feature = "mceliece460896f",
-            feature = "mceliece6688128f",
-            feature = "mceliece6960119f",
-            feature = "mceliece8192128f"
-        ))]
-        {
-            if pk_gen(
-                pk,
-                sub!(mut sk, 40, IRR_BYTES),
-                &mut perm,
-                &mut pi,
-                &mut pivots,
-            )? != 0
-            {
-                continue;
-            }
-        }
-        #[cfg(any(
-            feature = "mceliece348864",
-            feature = "mceliece460896",
-            feature = "mceliece6688128",
-            feature = "mceliece6960119",
-            feature = "mceliece8192128"
-        ))]
-        {
-            if pk_gen(pk, sub!(mut sk, 40, IRR_BYTES), &mut perm, &mut pi)? != 0 {
-                continue;
-            }
+        if pk_gen(pk, sub!(mut sk, 40, IRR_BYTES), &perm, &mut pi, &mut pivots) != 0 {
+            continue;
         }
 
         controlbitsfrompermutation(
             &mut sk[(40 + IRR_BYTES)..(40 + IRR_BYTES + COND_BYTES)],
-            &mut pi,
+            &pi[..],
             GFBITS,
             1 << GFBITS,
         );
@@ -344,14 +485,7 @@ pub fn crypto_kem_keypair(
 
         // storing positions of the 32 pivots
 
-        #[cfg(any(
-            feature = "mceliece348864",
-            feature = "mceliece460896",
-            feature = "mceliece6688128",
-            feature = "mceliece6960119",
-            feature = "mceliece8192128"
-        ))]
-        {
+        if !SEMI_SYSTEMATIC {
             pivots = 0xFFFFFFFF;
         }
 
@@ -360,115 +494,1305 @@ pub fn crypto_kem_keypair(
         break;
     }
 
+    zeroize!(seed, r, f, irr, perm, pi);
+}
+
+/// Recompute the public key `pk` belonging to the secret key `sk`.
+///
+/// The support is derived from the control bits and, for the semi-systematic (`f`) variants,
+/// the column swaps are taken from the stored pivots. The result is identical to the public key
+/// generated together with `sk`. Fails with `Error::InvalidSecretKey` if `sk` does not yield
+/// a systematic matrix or the stored pivots do not match the recomputed ones.
+pub fn crypto_kem_public_key_from_secret_key(
+    pk: &mut [u8; CRYPTO_PUBLICKEYBYTES],
+    sk: &[u8; CRYPTO_SECRETKEYBYTES],
+) -> Result<(), Error> {
+    let mut l: LargeArray<Gf, SYS_N> = large_array(0);
+    let mut pi: LargeArray<i16, { 1 << GFBITS }> = large_array(0i16);
+
+    support_gen(&mut l, sub!(sk, 40 + IRR_BYTES, COND_BYTES));
+
+    // the support elements are the bit-reversed permutation entries
+    for i in 0..SYS_N {
+        pi[i] = bitrev(l[i]) as i16;
+    }
+
+    let pivots = load8(sub!(sk, 32, 8));
+    let mut recomputed = 0u64;
+
+    let mut ret = 0;
+    if SEMI_SYSTEMATIC {
+        ret = restore_columns(&mut pi, pivots);
+    }
+
+    if ret == 0 {
+        ret = pk_gen_from_permutation(pk, sub!(sk, 40, IRR_BYTES), &mut pi, &mut recomputed);
+    }
+
+    zeroize!(l, pi);
+
+    if ret != 0 || (SEMI_SYSTEMATIC && recomputed != pivots) {
+        return Err(Error::InvalidSecretKey);
+    }
+
+    Ok(())
+}
+
+/// Check the public key `pk` received from another party.
+///
+/// Returns `Error::InvalidLength` if `pk` has the wrong length, `Error::InvalidPadding` if
+/// the padding bits of any row are non-zero (only variants with `PK_NCOLS % 8 != 0` have padding bits)
+/// and `Error::InvalidPublicKey` if any row of the matrix is all-zero.
+/// Passing this check means `crypto_kem_enc` accepts `pk`.
+pub fn validate_public_key(pk: &[u8]) -> Result<(), Error> {
+    let pk: &[u8; CRYPTO_PUBLICKEYBYTES] = as_array(pk)?;
+
+    padding_outcome(check_pk_padding(pk))?;
+
+    // the key is public, hence the rows can be checked with early returns
+    if pk
+        .chunks(PK_ROW_BYTES)
+        .any(|row| row.iter().all(|&b| b == 0))
+    {
+        return Err(Error::InvalidPublicKey);
+    }
+
     Ok(())
 }
 
+/// Compute the fingerprint of the public key `pk`, a short identifier for logs, storage and protocol messages.
+pub fn public_key_fingerprint(pk: &[u8; CRYPTO_PUBLICKEYBYTES]) -> Fingerprint {
+    let mut hasher = FingerprintHasher::new(CRYPTO_PRIMITIVE);
+    hasher.update(pk);
+    hasher.finalize()
+}
+
+/// Compute the fingerprint of a public key read from `reader` without loading the whole key into memory.
+///
+/// Exactly `CRYPTO_PUBLICKEYBYTES` bytes are read. If `reader` ends before,
+/// `Error::InvalidLength` reports the number of bytes read. Read errors are returned as `Error::Io`.
+#[cfg(feature = "std")]
+pub fn public_key_fingerprint_from_reader(
+    mut reader: impl std::io::Read,
+) -> Result<Fingerprint, Error> {
+    let mut hasher = FingerprintHasher::new(CRYPTO_PRIMITIVE);
+    let mut buf = [0u8; 4096];
+    let mut remaining = CRYPTO_PUBLICKEYBYTES;
+
+    while remaining > 0 {
+        let len = remaining.min(buf.len());
+        let read = read_full(&mut reader, &mut buf[..len])?;
+        if read < len {
+            return Err(Error::InvalidLength {
+                expected: CRYPTO_PUBLICKEYBYTES,
+                actual: CRYPTO_PUBLICKEYBYTES - remaining + read,
+            });
+        }
+
+        hasher.update(&buf[..len]);
+        remaining -= len;
+    }
+
+    Ok(hasher.finalize())
+}
+
+/// Check the structure of the secret key `sk`.
+///
+/// The following properties are verified:
+/// - the Goppa polynomial (stored without its leading coefficient, hence monic of degree `SYS_T`)
+///   is canonically encoded and irreducible,
+/// - the support elements given by the control bits are distinct,
+/// - the pivots equal `0xFFFFFFFF` for the non-`f` variants and contain 32 positions otherwise.
+///
+/// If `check_seed` is set, the seed stored in the first 32 bytes is expanded and the result has
+/// to be identical to `sk`. This takes as long as the key generation itself.
+/// The expansion requires the `alloc` feature, otherwise `Error::RequiresAlloc` is returned,
+/// because the key pair and the matrix of the key generation would exceed `MAX_STACK_USAGE`.
+/// Returns `Error::InvalidLength` if `sk` has the wrong length and `Error::InvalidSecretKey`
+/// if any check fails. The random string `s` has no structure and is not checked.
+pub fn validate_secret_key(sk: &[u8], check_seed: bool) -> Result<(), Error> {
+    let sk: &[u8; CRYPTO_SECRETKEYBYTES] = as_array(sk)?;
+
+    if check_seed && cfg!(not(feature = "alloc")) {
+        return Err(Error::RequiresAlloc);
+    }
+
+    // pivots
+
+    let pivots = load8(sub!(sk, 32, 8));
+
+    if (SEMI_SYSTEMATIC && pivots.count_ones() != 32) || (!SEMI_SYSTEMATIC && pivots != 0xFFFFFFFF)
+    {
+        return Err(Error::InvalidSecretKey);
+    }
+
+    // Goppa polynomial
+
+    let mut g = [0u16; SYS_T];
+    let mut invalid = 0u16;
+
+    for (i, chunk) in sk[40..40 + IRR_BYTES].chunks(2).enumerate() {
+        g[i] = load_gf(sub!(chunk, 0, 2));
+        invalid |= (chunk[1] as u16) << 8 | chunk[0] as u16;
+        invalid ^= g[i];
+    }
+
+    if invalid != 0 || irreducibility_check(&g) != 0 {
+        zeroize!(g);
+        return Err(Error::InvalidSecretKey);
+    }
+
+    zeroize!(g);
+
+    // support
+
+    let mut l: LargeArray<Gf, SYS_N> = large_array(0);
+    let mut sorted: LargeArray<u64, SYS_N> = large_array(0u64);
+
+    support_gen(&mut l, sub!(sk, 40 + IRR_BYTES, COND_BYTES));
+
+    for i in 0..SYS_N {
+        sorted[i] = l[i] as u64;
+    }
+
+    uint64_sort(&mut sorted);
+
+    let mut duplicates = 0u64;
+    for i in 1..SYS_N {
+        duplicates |= ((sorted[i - 1] ^ sorted[i]).wrapping_sub(1)) >> 63;
+    }
+
+    zeroize!(l, sorted);
+
+    if duplicates != 0 {
+        return Err(Error::InvalidSecretKey);
+    }
+
+    // seed
+
+    #[cfg(feature = "alloc")]
+    if check_seed {
+        let mut pk: LargeArray<u8, CRYPTO_PUBLICKEYBYTES> = large_array(0u8);
+        let mut expanded: LargeArray<u8, CRYPTO_SECRETKEYBYTES> = large_array(0u8);
+
+        crypto_kem_keypair_from_seed(&mut pk, &mut expanded, sub!(sk, 0, 32));
+
+        let mut diff = 0u8;
+        for i in 0..CRYPTO_SECRETKEYBYTES {
+            diff |= expanded[i] ^ sk[i];
+        }
+
+        zeroize!(expanded);
+
+        if diff != 0 {
+            return Err(Error::InvalidSecretKey);
+        }
+    }
+
+    Ok(())
+}
+
+/// KEM Keypair generation into byte slices.
+///
+/// See `crypto_kem_keypair`. Returns `Error::InvalidLength` without writing anything
+/// unless `pk` has `CRYPTO_PUBLICKEYBYTES` and `sk` has `CRYPTO_SECRETKEYBYTES` bytes.
+pub fn crypto_kem_keypair_slice(
+    pk: &mut [u8],
+    sk: &mut [u8],
+    rng: &mut (impl CryptoRng + RngCore),
+) -> Result<(), Error> {
+    crypto_kem_keypair(as_array_mut(pk)?, as_array_mut(sk)?, rng)
+}
+
+/// KEM Encapsulation with byte slices.
+///
+/// See `crypto_kem_enc`. The public key is borrowed in place, e.g. from a memory-mapped file
+/// or a larger protocol message, and never copied. Returns `Error::InvalidLength` without writing
+/// anything unless `c`, `key` and `pk` have `CRYPTO_CIPHERTEXTBYTES`, `CRYPTO_BYTES` and
+/// `CRYPTO_PUBLICKEYBYTES` bytes respectively.
+pub fn crypto_kem_enc_slice(
+    c: &mut [u8],
+    key: &mut [u8],
+    pk: &[u8],
+    rng: &mut (impl CryptoRng + RngCore),
+) -> Result<(), Error> {
+    crypto_kem_enc(as_array_mut(c)?, as_array_mut(key)?, as_array(pk)?, rng)
+}
+
+/// KEM Decapsulation with byte slices.
+///
+/// See `crypto_kem_dec`. Returns `Error::InvalidLength` without writing anything
+/// unless `key`, `c` and `sk` have `CRYPTO_BYTES`, `CRYPTO_CIPHERTEXTBYTES` and
+/// `CRYPTO_SECRETKEYBYTES` bytes respectively.
+pub fn crypto_kem_dec_slice(key: &mut [u8], c: &[u8], sk: &[u8]) -> Result<(), Error> {
+    crypto_kem_dec(as_array_mut(key)?, as_array(c)?, as_array(sk)?)
+}
+
+/// KEM Keypair generation returning the keys by value.
+///
+/// See `crypto_kem_keypair`. Both keys are allocated on the heap.
+#[cfg(feature = "alloc")]
+pub fn keypair(rng: &mut (impl CryptoRng + RngCore)) -> Result<(PublicKey, SecretKey), Error> {
+    let mut pk = PublicKey::zeroed();
+    let mut sk = SecretKey::zeroed();
+
+    crypto_kem_keypair(&mut pk.0, &mut sk.0, rng)?;
+
+    Ok((pk, sk))
+}
+
+/// Expand the 32-byte seed stored at the beginning of a secret key into the keypair.
+///
+/// Storing only the seed is the compact form of the secret key.
+/// The result is identical to the keypair, which `crypto_kem_keypair` generated with this seed.
+#[cfg(feature = "alloc")]
+pub fn expand_secret_key(seed: &[u8; 32]) -> (PublicKey, SecretKey) {
+    let mut pk = PublicKey::zeroed();
+    let mut sk = SecretKey::zeroed();
+
+    crypto_kem_keypair_from_seed(&mut pk.0, &mut sk.0, seed);
+
+    (pk, sk)
+}
+
+/// Recompute the public key belonging to `sk`.
+///
+/// See `crypto_kem_public_key_from_secret_key`.
+#[cfg(feature = "alloc")]
+pub fn public_key_from_secret_key(sk: &SecretKey) -> Result<PublicKey, Error> {
+    let mut pk = PublicKey::zeroed();
+
+    crypto_kem_public_key_from_secret_key(&mut pk.0, &sk.0)?;
+
+    Ok(pk)
+}
+
+/// KEM Encapsulation returning the ciphertext and shared secret by value.
+///
+/// See `crypto_kem_enc`. Fails if the padding bits of `pk` are non-zero.
+#[cfg(feature = "alloc")]
+pub fn encapsulate(
+    pk: &PublicKey,
+    rng: &mut (impl CryptoRng + RngCore),
+) -> Result<(Ciphertext, SharedSecret), Error> {
+    let mut ct = Ciphertext([0u8; CRYPTO_CIPHERTEXTBYTES]);
+    let mut ss = SharedSecret([0u8; CRYPTO_BYTES]);
+
+    crypto_kem_enc(&mut ct.0, &mut ss.0, &pk.0, rng)?;
+
+    Ok((ct, ss))
+}
+
+/// KEM Encapsulation with caller-supplied randomness, returning the results by value.
+///
+/// See `crypto_kem_enc_derand`.
+#[cfg(feature = "alloc")]
+pub fn encapsulate_derand(
+    pk: &PublicKey,
+    coins: &[u8; 32],
+) -> Result<(Ciphertext, SharedSecret), Error> {
+    let mut ct = Ciphertext([0u8; CRYPTO_CIPHERTEXTBYTES]);
+    let mut ss = SharedSecret([0u8; CRYPTO_BYTES]);
+
+    crypto_kem_enc_derand(&mut ct.0, &mut ss.0, &pk.0, coins)?;
+
+    Ok((ct, ss))
+}
+
+/// KEM Encapsulation with a public key read from `reader`, returning the results by value.
+///
+/// See `crypto_kem_enc_from_reader`. Also returns the fingerprint of the public key.
+#[cfg(feature = "std")]
+pub fn encapsulate_from_reader(
+    reader: impl std::io::Read,
+    rng: &mut (impl CryptoRng + RngCore),
+) -> Result<(Ciphertext, SharedSecret, Fingerprint), Error> {
+    let mut ct = Ciphertext([0u8; CRYPTO_CIPHERTEXTBYTES]);
+    let mut ss = SharedSecret([0u8; CRYPTO_BYTES]);
+
+    let fingerprint = crypto_kem_enc_from_reader(&mut ct.0, &mut ss.0, reader, rng)?;
+
+    Ok((ct, ss, fingerprint))
+}
+
+/// KEM Decapsulation returning the shared secret by value.
+///
+/// See `crypto_kem_dec`. Fails if the padding bits of `ct` are non-zero.
+#[cfg(feature = "alloc")]
+pub fn decapsulate(ct: &Ciphertext, sk: &SecretKey) -> Result<SharedSecret, Error> {
+    let mut ss = SharedSecret([0u8; CRYPTO_BYTES]);
+
+    crypto_kem_dec(&mut ss.0, &ct.0, &sk.0)?;
+
+    Ok(ss)
+}
+
 #[cfg(test)]
 mod tests {
-    #[cfg(all(feature = "mceliece8192128f", test))]
+    use super::super::CRYPTO_PRIMITIVE;
     use super::*;
-    #[cfg(all(feature = "mceliece8192128f", test))]
-    use crate::randombytes::AesState;
-    #[cfg(all(feature = "mceliece8192128f", test))]
-    use std::convert::TryFrom;
+    // without `alloc`, the RNG is only used by tests compiled for some of the variants
+    #[allow(unused_imports)]
+    use crate::randombytes::{AesState, RNGState};
 
     #[test]
-    #[cfg(feature = "mceliece8192128f")]
-    fn test_crypto_kem_dec() -> Result<(), Box<dyn error::Error>> {
-        use crate::api::{CRYPTO_CIPHERTEXTBYTES, CRYPTO_SECRETKEYBYTES};
+    #[cfg(feature = "alloc")]
+    fn test_stack_usage() {
+        let result = std::thread::Builder::new()
+            .stack_size(crate::MAX_STACK_USAGE)
+            .spawn(|| {
+                let mut rng = AesState::new();
+                rng.randombytes_init([5u8; 48]);
+
+                let (pk, sk) = keypair(&mut rng).unwrap();
+                let (ct, ss_bob) = encapsulate(&pk, &mut rng).unwrap();
+                let ss_alice = decapsulate(&ct, &sk).unwrap();
+
+                ss_alice.as_bytes() == ss_bob.as_bytes()
+            })
+            .unwrap()
+            .join();
+
+        assert_eq!(result.ok(), Some(true));
+    }
 
-        let mut sk = crate::TestData::new().u8vec("mceliece8192128f_sk1");
-        let mut c = crate::TestData::new().u8vec("mceliece8192128f_ct1");
-        let mut test_key = [0u8; 32];
-        let compare_key = crate::TestData::new().u8vec("mceliece8192128f_operations_ss");
+    variant_tests! {
+        [mceliece6960119]
 
-        crypto_kem_dec(
-            &mut test_key,
-            sub!(mut c, 0, CRYPTO_CIPHERTEXTBYTES),
-            sub!(mut sk, 0, CRYPTO_SECRETKEYBYTES),
-        )?;
+        #[test]
+        #[cfg(feature = "alloc")]
+        fn test_typed_api() -> Result<(), Error> {
+            let mut rng = AesState::new();
+            rng.randombytes_init([3u8; 48]);
 
-        assert_eq!(test_key, compare_key.as_slice());
+            let (pk, sk) = keypair(&mut rng)?;
+            let (ct, ss_bob) = encapsulate(&pk, &mut rng)?;
+            let ss_alice = decapsulate(&ct, &sk)?;
+            assert_eq!(ss_alice.as_bytes(), ss_bob.as_bytes());
 
-        Ok(())
+            // set the padding bits of the first row
+            let mut pk_bytes = pk.as_bytes().to_vec();
+            pk_bytes[PK_ROW_BYTES - 1] |= 0x80;
+            assert!(encapsulate(&PublicKey::from_bytes(&pk_bytes)?, &mut rng).is_err());
+
+            let mut ct_bytes = *ct.as_bytes();
+            ct_bytes[SYND_BYTES - 1] |= 0x80;
+            assert!(decapsulate(&Ciphertext::from_bytes(&ct_bytes)?, &sk).is_err());
+
+            Ok(())
+        }
     }
 
-    #[test]
-    #[cfg(feature = "mceliece8192128f")]
-    fn test_crypto_kem_enc() -> Result<(), Box<dyn error::Error>> {
-        use crate::api::{CRYPTO_BYTES, CRYPTO_CIPHERTEXTBYTES, CRYPTO_PUBLICKEYBYTES};
+    variant_tests! {
+        [mceliece348864, mceliece6960119, mceliece8192128]
+
+        #[test]
+        #[cfg(feature = "alloc")]
+        fn test_enc_derand() -> Result<(), Error> {
+            let (pk, sk) = expand_secret_key(&[0u8; 32]);
+
+            let (ct, ss) = encapsulate_derand(&pk, &[1u8; 32])?;
+            let (ct_again, ss_again) = encapsulate_derand(&pk, &[1u8; 32])?;
+            assert_eq!(ct_again, ct);
+            assert_eq!(ss_again.as_bytes(), ss.as_bytes());
+            assert_eq!(decapsulate(&ct, &sk)?.as_bytes(), ss.as_bytes());
+
+            let (ct_other, _) = encapsulate_derand(&pk, &[2u8; 32])?;
+            assert_ne!(ct_other, ct);
+
+            // the derandomized encapsulation encrypts the error vector sampled from the expanded
+            // coins
+            let mut e = [0u8; SYS_N / 8];
+            gen_e(&mut e, &mut Shake256Rng::new(ENC_COINS_DOMAIN, &[1u8; 32]))?;
+            let mut c = [0u8; CRYPTO_CIPHERTEXTBYTES];
+            let mut key = [0u8; CRYPTO_BYTES];
+            crypto_kem_enc_with_error_vector(&mut c, &mut key, pk.as_bytes(), &e)?;
+            assert_eq!(&c, ct.as_bytes());
+            assert_eq!(&key, ss.as_bytes());
+
+            // pin the output for 348864, such that changes of the expansion are noticed
+            if CRYPTO_PRIMITIVE == "mceliece348864" {
+                let mut digest = [0u8; 16];
+                shake256(&mut digest, &[&c[..], &key[..]].concat());
+                assert_eq!(
+                    digest,
+                    [73, 204, 84, 142, 249, 230, 194, 215, 210, 237, 2, 232, 149, 249, 63, 91]
+                );
+            }
+
+            // error vectors of wrong weight are rejected without touching the outputs
+            let mut c_out = [0xAAu8; CRYPTO_CIPHERTEXTBYTES];
+            let mut key_out = [0xAAu8; CRYPTO_BYTES];
+            let first_set = e.iter().position(|&b| b != 0).unwrap();
+            let mut e_light = e;
+            e_light[first_set] &= e_light[first_set] - 1;
+            assert_eq!(
+                crypto_kem_enc_with_error_vector(&mut c_out, &mut key_out, pk.as_bytes(), &e_light),
+                Err(Error::InvalidWeight {
+                    expected: SYS_T,
+                    actual: SYS_T - 1,
+                })
+            );
+            assert_eq!(
+                crypto_kem_enc_with_error_vector(
+                    &mut c_out,
+                    &mut key_out,
+                    pk.as_bytes(),
+                    &[0xFF; SYS_N / 8]
+                ),
+                Err(Error::InvalidWeight {
+                    expected: SYS_T,
+                    actual: SYS_N,
+                })
+            );
+            assert_eq!(c_out, [0xAAu8; CRYPTO_CIPHERTEXTBYTES]);
+            assert_eq!(key_out, [0xAAu8; CRYPTO_BYTES]);
+
+            Ok(())
+        }
+    }
+
+    variant_tests! {
+        [mceliece348864, mceliece6960119]
+
+        // without `alloc`, the key generation of `mceliece6960119` does not fit on the stack of the
+        // test threads
+        #[test]
+        fn test_slice_api() -> Result<(), Error> {
+            if !cfg!(feature = "alloc") && CRYPTO_PRIMITIVE == "mceliece6960119" {
+                return Ok(());
+            }
+
+            let mut rng = AesState::new();
+            rng.randombytes_init([19u8; 48]);
+
+            // keys embedded at odd offsets of larger buffers, as in a protocol message
+            let mut pk_buf = vec![0u8; 3 + CRYPTO_PUBLICKEYBYTES + 5];
+            let mut sk_buf = vec![0u8; 1 + CRYPTO_SECRETKEYBYTES];
+            let pk = &mut pk_buf[3..3 + CRYPTO_PUBLICKEYBYTES];
+            let sk = &mut sk_buf[1..];
+            crypto_kem_keypair_slice(pk, sk, &mut rng)?;
+
+            let mut c = [0u8; CRYPTO_CIPHERTEXTBYTES];
+            let mut key_bob = [0u8; CRYPTO_BYTES];
+            let mut key_alice = [0u8; CRYPTO_BYTES];
+            crypto_kem_enc_slice(&mut c, &mut key_bob, pk, &mut rng)?;
+            crypto_kem_dec_slice(&mut key_alice, &c, sk)?;
+            assert_eq!(key_alice, key_bob);
+
+            // wrong lengths are reported without touching the outputs
+            let mut c_out = [0xAAu8; CRYPTO_CIPHERTEXTBYTES];
+            let mut key_out = [0xAAu8; CRYPTO_BYTES];
+            assert_eq!(
+                crypto_kem_enc_slice(&mut c_out, &mut key_out, &pk_buf[3..], &mut rng),
+                Err(Error::InvalidLength {
+                    expected: CRYPTO_PUBLICKEYBYTES,
+                    actual: CRYPTO_PUBLICKEYBYTES + 5,
+                })
+            );
+            assert_eq!(
+                crypto_kem_enc_slice(
+                    &mut c_out[1..],
+                    &mut key_out,
+                    &pk_buf[3..3 + CRYPTO_PUBLICKEYBYTES],
+                    &mut rng
+                ),
+                Err(Error::InvalidLength {
+                    expected: CRYPTO_CIPHERTEXTBYTES,
+                    actual: CRYPTO_CIPHERTEXTBYTES - 1,
+                })
+            );
+            assert_eq!(
+                crypto_kem_dec_slice(&mut key_out, &c, &sk_buf),
+                Err(Error::InvalidLength {
+                    expected: CRYPTO_SECRETKEYBYTES,
+                    actual: CRYPTO_SECRETKEYBYTES + 1,
+                })
+            );
+            assert_eq!(c_out, [0xAAu8; CRYPTO_CIPHERTEXTBYTES]);
+            assert_eq!(key_out, [0xAAu8; CRYPTO_BYTES]);
+
+            let mut pk_short = vec![0xAAu8; CRYPTO_PUBLICKEYBYTES - 1];
+            assert_eq!(
+                crypto_kem_keypair_slice(&mut pk_short, &mut sk_buf[1..], &mut rng),
+                Err(Error::InvalidLength {
+                    expected: CRYPTO_PUBLICKEYBYTES,
+                    actual: CRYPTO_PUBLICKEYBYTES - 1,
+                })
+            );
+            assert!(pk_short.iter().all(|&b| b == 0xAA));
+
+            Ok(())
+        }
+    }
 
-        let mut c = [0u8; CRYPTO_CIPHERTEXTBYTES];
-        let mut ss = [0u8; CRYPTO_BYTES];
-        let mut pk = crate::TestData::new().u8vec("mceliece8192128f_pk1");
-        assert_eq!(pk.len(), CRYPTO_PUBLICKEYBYTES);
+    variant_tests! {
+        [mceliece348864f]
 
-        let compare_ss = crate::TestData::new().u8vec("mceliece8192128f_operations_ss");
-        let compare_ct = crate::TestData::new().u8vec("mceliece8192128f_operations_enc1_ct");
+        #[test]
+        #[cfg(feature = "alloc")]
+        fn test_expand_secret_key() -> Result<(), Error> {
+            let mut rng = AesState::new();
+            rng.randombytes_init([11u8; 48]);
 
-        // set the same seed as in C implementation
-        let entropy_input = <[u8; 48]>::try_from(
-            crate::TestData::new()
-                .u8vec("mceliece8192128f_operations_entropy_input")
-                .as_slice(),
-        )
-        .unwrap();
+            let (pk, sk) = keypair(&mut rng)?;
+            let (pk_expanded, sk_expanded) = expand_secret_key(sk.seed());
+            assert_eq!(pk_expanded, pk);
+            assert_eq!(sk_expanded.as_bytes(), sk.as_bytes());
 
-        let mut rng_state = AesState::new();
-        rng_state.randombytes_init(entropy_input);
+            let (pk, sk) = expand_secret_key(&[0x42; 32]);
+            let (pk_expanded, sk_expanded) = expand_secret_key(sk.seed());
+            assert_eq!(pk_expanded, pk);
+            assert_eq!(sk_expanded.as_bytes(), sk.as_bytes());
 
-        let mut second_seed = [0u8; 33];
-        second_seed[0] = 64;
+            Ok(())
+        }
+    }
 
-        rng_state.randombytes(&mut second_seed[1..])?;
+    variant_tests! {
+        [mceliece348864, mceliece348864f, mceliece6960119f]
 
-        crypto_kem_enc(
-            &mut c,
-            &mut ss,
-            sub!(mut pk, 0, CRYPTO_PUBLICKEYBYTES),
-            &mut rng_state,
-        )?;
+        #[test]
+        #[cfg(feature = "alloc")]
+        fn test_public_key_from_secret_key() -> Result<(), Error> {
+            let mut rng = AesState::new();
+            rng.randombytes_init([17u8; 48]);
 
-        assert_eq!(ss, compare_ss.as_slice());
+            for _ in 0..2 {
+                let (pk, sk) = keypair(&mut rng)?;
+                assert_eq!(public_key_from_secret_key(&sk)?, pk);
+            }
 
-        assert_eq!(c, compare_ct.as_slice());
+            Ok(())
+        }
+    }
 
-        Ok(())
+    variant_tests! {
+        [mceliece348864f]
+
+        #[test]
+        fn test_public_key_from_secret_key_invalid_pivots() -> Result<(), Error> {
+            let mut pk = vec![0u8; CRYPTO_PUBLICKEYBYTES];
+            let mut sk = vec![0u8; CRYPTO_SECRETKEYBYTES];
+            crypto_kem_keypair_from_seed(
+                sub!(mut pk, 0, CRYPTO_PUBLICKEYBYTES),
+                sub!(mut sk, 0, CRYPTO_SECRETKEYBYTES),
+                &[5u8; 32],
+            );
+
+            // more or less than 32 pivots
+            let mut corrupted = sk.clone();
+            corrupted[32] ^= 0x80;
+            assert_eq!(
+                crypto_kem_public_key_from_secret_key(
+                    sub!(mut pk, 0, CRYPTO_PUBLICKEYBYTES),
+                    sub!(corrupted, 0, CRYPTO_SECRETKEYBYTES),
+                ),
+                Err(Error::InvalidSecretKey)
+            );
+
+            // 32 pivots at the wrong positions
+            let mut corrupted = sk.clone();
+            store8(sub!(mut corrupted, 32, 8), 0xFFFFFFFF00000000);
+            let mut pk2 = vec![0u8; CRYPTO_PUBLICKEYBYTES];
+            let result = crypto_kem_public_key_from_secret_key(
+                sub!(mut pk2, 0, CRYPTO_PUBLICKEYBYTES),
+                sub!(corrupted, 0, CRYPTO_SECRETKEYBYTES),
+            );
+            assert!(matches!(result, Err(Error::InvalidSecretKey)));
+
+            Ok(())
+        }
+    }
+
+    variant_tests! {
+        [mceliece348864, mceliece6960119, mceliece6960119pcf]
+
+        /// Reader returning at most 7 bytes per call, like a slow network connection
+        #[cfg(feature = "std")]
+        struct SlowReader<'a>(&'a [u8]);
+
+        #[cfg(feature = "std")]
+        impl std::io::Read for SlowReader<'_> {
+            fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+                let len = buf.len().min(self.0.len()).min(7);
+                buf[..len].copy_from_slice(&self.0[..len]);
+                self.0 = &self.0[len..];
+                Ok(len)
+            }
+        }
+
+        /// Reader failing with the given error kind
+        #[cfg(feature = "std")]
+        struct FailingReader(std::io::ErrorKind);
+
+        #[cfg(feature = "std")]
+        impl std::io::Read for FailingReader {
+            fn read(&mut self, _buf: &mut [u8]) -> std::io::Result<usize> {
+                Err(self.0.into())
+            }
+        }
+
+        #[test]
+        #[cfg(feature = "std")]
+        #[allow(clippy::manual_is_multiple_of)]
+        fn test_encapsulate_from_reader() -> Result<(), Error> {
+            let (pk, sk) = expand_secret_key(&[21u8; 32]);
+
+            let mut rng = AesState::new();
+            rng.randombytes_init([22u8; 48]);
+            let (ct, ss) = encapsulate(&pk, &mut rng)?;
+
+            let mut rng = AesState::new();
+            rng.randombytes_init([22u8; 48]);
+            let (ct_streamed, ss_streamed, fingerprint) =
+                encapsulate_from_reader(SlowReader(pk.as_bytes()), &mut rng)?;
+
+            assert_eq!(ct_streamed, ct);
+            assert_eq!(ss_streamed.as_bytes(), ss.as_bytes());
+            assert_eq!(fingerprint, pk.fingerprint());
+            assert_eq!(decapsulate(&ct_streamed, &sk)?.as_bytes(), ss.as_bytes());
+
+            // truncated input
+            let truncated = &pk.as_bytes()[..CRYPTO_PUBLICKEYBYTES - 1];
+            assert_eq!(
+                encapsulate_from_reader(truncated, &mut rng).err(),
+                Some(Error::InvalidLength {
+                    expected: CRYPTO_PUBLICKEYBYTES,
+                    actual: CRYPTO_PUBLICKEYBYTES - 1,
+                })
+            );
+
+            // read errors
+            let mut failing = std::io::Read::chain(
+                &pk.as_bytes()[..1000],
+                FailingReader(std::io::ErrorKind::ConnectionReset),
+            );
+            let mut c = [0xAAu8; CRYPTO_CIPHERTEXTBYTES];
+            let mut key = [0xAAu8; CRYPTO_BYTES];
+            assert_eq!(
+                crypto_kem_enc_from_reader(&mut c, &mut key, &mut failing, &mut rng),
+                Err(Error::Io(std::io::ErrorKind::ConnectionReset))
+            );
+            assert_eq!(c, [0u8; CRYPTO_CIPHERTEXTBYTES]);
+            assert_eq!(key, [0u8; CRYPTO_BYTES]);
+
+            // padding bits
+            if PK_NCOLS % 8 != 0 {
+                let mut padded = pk.as_bytes().to_vec();
+                padded[PK_ROW_BYTES - 1] |= 0x80;
+                assert_eq!(
+                    encapsulate_from_reader(&padded[..], &mut rng).err(),
+                    Some(Error::InvalidPadding)
+                );
+            }
+
+            Ok(())
+        }
+    }
+
+    variant_tests! {
+        [mceliece348864f, mceliece6960119, mceliece6960119f]
+
+        // the key generation of `mceliece6960119` does not fit on the stack of the test threads
+        // without `alloc`
+        #[test]
+        #[cfg(feature = "alloc")]
+        #[allow(clippy::manual_is_multiple_of)]
+        fn test_validate_public_key() -> Result<(), Error> {
+            let mut pk = vec![0u8; CRYPTO_PUBLICKEYBYTES];
+            let mut sk = vec![0u8; CRYPTO_SECRETKEYBYTES];
+            crypto_kem_keypair_from_seed(
+                sub!(mut pk, 0, CRYPTO_PUBLICKEYBYTES),
+                sub!(mut sk, 0, CRYPTO_SECRETKEYBYTES),
+                &[3u8; 32],
+            );
+
+            validate_public_key(&pk)?;
+
+            assert_eq!(
+                validate_public_key(&pk[..CRYPTO_PUBLICKEYBYTES - 1]),
+                Err(Error::InvalidLength {
+                    expected: CRYPTO_PUBLICKEYBYTES,
+                    actual: CRYPTO_PUBLICKEYBYTES - 1,
+                })
+            );
+
+            let mut degenerate = pk.clone();
+            degenerate[5 * PK_ROW_BYTES..6 * PK_ROW_BYTES].fill(0);
+            assert_eq!(
+                validate_public_key(&degenerate),
+                Err(Error::InvalidPublicKey)
+            );
+
+            if PK_NCOLS % 8 != 0 {
+                let mut padded = pk.clone();
+                padded[PK_ROW_BYTES - 1] |= 0x80;
+                assert_eq!(validate_public_key(&padded), Err(Error::InvalidPadding));
+            }
+
+            Ok(())
+        }
+    }
+
+    variant_tests! {
+        [mceliece348864, mceliece6960119]
+
+        #[test]
+        #[cfg(feature = "std")]
+        fn test_public_key_fingerprint() {
+            let mut pk = vec![0u8; CRYPTO_PUBLICKEYBYTES];
+            let mut sk = vec![0u8; CRYPTO_SECRETKEYBYTES];
+            crypto_kem_keypair_from_seed(
+                sub!(mut pk, 0, CRYPTO_PUBLICKEYBYTES),
+                sub!(mut sk, 0, CRYPTO_SECRETKEYBYTES),
+                &[0u8; 32],
+            );
+
+            let fingerprint = public_key_fingerprint(sub!(pk, 0, CRYPTO_PUBLICKEYBYTES));
+            let streamed = public_key_fingerprint_from_reader(&pk[..]).unwrap();
+            assert_eq!(streamed, fingerprint);
+
+            if CRYPTO_PRIMITIVE == "mceliece348864" {
+                assert_eq!(
+                    fingerprint.to_string(),
+                    "21ccd90026352f71db555f5badaff3debd274016e2700db9929ff54c59bf70e3"
+                );
+            }
+
+            let mut other = pk.clone();
+            other[1000] ^= 1;
+            assert_ne!(
+                public_key_fingerprint(sub!(other, 0, CRYPTO_PUBLICKEYBYTES)),
+                fingerprint
+            );
+
+            // trailing data is not consumed
+            let mut reader = std::io::Cursor::new([&pk[..], &[7u8; 3]].concat());
+            assert_eq!(
+                public_key_fingerprint_from_reader(&mut reader).unwrap(),
+                fingerprint
+            );
+            assert_eq!(reader.position(), CRYPTO_PUBLICKEYBYTES as u64);
+
+            assert_eq!(
+                public_key_fingerprint_from_reader(&pk[..CRYPTO_PUBLICKEYBYTES - 1]),
+                Err(Error::InvalidLength {
+                    expected: CRYPTO_PUBLICKEYBYTES,
+                    actual: CRYPTO_PUBLICKEYBYTES - 1,
+                })
+            );
+            assert_eq!(
+                public_key_fingerprint_from_reader(SlowReader(&pk[..5000])),
+                Err(Error::InvalidLength {
+                    expected: CRYPTO_PUBLICKEYBYTES,
+                    actual: 5000,
+                })
+            );
+            assert_eq!(
+                public_key_fingerprint_from_reader(FailingReader(
+                    std::io::ErrorKind::ConnectionReset
+                )),
+                Err(Error::Io(std::io::ErrorKind::ConnectionReset))
+            );
+        }
+    }
+
+    variant_tests! {
+        [mceliece348864, mceliece460896f]
+
+        // the keys of `mceliece460896f` and the expansion of the seed do not fit on the stack
+        // without `alloc`
+        #[test]
+        #[cfg(feature = "alloc")]
+        fn test_validate_secret_key() -> Result<(), Error> {
+            let mut pk = vec![0u8; CRYPTO_PUBLICKEYBYTES];
+            let mut sk = vec![0u8; CRYPTO_SECRETKEYBYTES];
+            crypto_kem_keypair_from_seed(
+                sub!(mut pk, 0, CRYPTO_PUBLICKEYBYTES),
+                sub!(mut sk, 0, CRYPTO_SECRETKEYBYTES),
+                &[9u8; 32],
+            );
+
+            validate_secret_key(&sk, false)?;
+            validate_secret_key(&sk, true)?;
+
+            assert_eq!(
+                validate_secret_key(&sk[1..], false),
+                Err(Error::InvalidLength {
+                    expected: CRYPTO_SECRETKEYBYTES,
+                    actual: CRYPTO_SECRETKEYBYTES - 1,
+                })
+            );
+
+            // pivots
+            let mut corrupted = sk.clone();
+            corrupted[36] ^= 1;
+            assert_eq!(
+                validate_secret_key(&corrupted, false),
+                Err(Error::InvalidSecretKey)
+            );
+
+            // non-canonical coefficient of the Goppa polynomial
+            let mut corrupted = sk.clone();
+            corrupted[41] ^= 0x80;
+            assert_eq!(
+                validate_secret_key(&corrupted, false),
+                Err(Error::InvalidSecretKey)
+            );
+
+            // reducible Goppa polynomial x^t
+            let mut corrupted = sk.clone();
+            corrupted[40..40 + IRR_BYTES].fill(0);
+            assert_eq!(
+                validate_secret_key(&corrupted, false),
+                Err(Error::InvalidSecretKey)
+            );
+
+            // structurally valid, but not the expansion of the seed
+            let mut corrupted = sk.clone();
+            corrupted[CRYPTO_SECRETKEYBYTES - 1] ^= 1;
+            validate_secret_key(&corrupted, false)?;
+            assert_eq!(
+                validate_secret_key(&corrupted, true),
+                Err(Error::InvalidSecretKey)
+            );
+
+            Ok(())
+        }
     }
 
     #[test]
-    #[cfg(feature = "mceliece8192128f")]
-    fn test_crypto_kem_keypair() -> Result<(), Box<dyn error::Error>> {
-        use crate::api::{CRYPTO_PUBLICKEYBYTES, CRYPTO_SECRETKEYBYTES};
+    fn test_plaintext_confirmation_variants() {
+        // exactly the `pc` and `pcf` variants append the confirmation hash
+        assert_eq!(PLAINTEXT_CONFIRMATION, CRYPTO_PRIMITIVE.contains("pc"));
+    }
 
-        let mut pk_input = [0; CRYPTO_PUBLICKEYBYTES].to_vec();
-        let mut sk_input = [0; CRYPTO_SECRETKEYBYTES].to_vec();
+    variant_tests! {
+        [mceliece348864pc, mceliece348864pcf]
+
+        #[test]
+        fn test_plaintext_confirmation() -> Result<(), Error> {
+            let mut pk = vec![0u8; CRYPTO_PUBLICKEYBYTES];
+            let mut sk = vec![0u8; CRYPTO_SECRETKEYBYTES];
+            let pk = sub!(mut pk, 0, CRYPTO_PUBLICKEYBYTES);
+            let sk = sub!(mut sk, 0, CRYPTO_SECRETKEYBYTES);
+            crypto_kem_keypair_from_seed(pk, sk, &[21u8; 32]);
+
+            let mut rng = AesState::new();
+            rng.randombytes_init([22u8; 48]);
+            let mut two_e = [2u8; 1 + SYS_N / 8];
+            gen_e(sub!(mut two_e, 1, SYS_N / 8), &mut rng)?;
+
+            let mut c = [0u8; CRYPTO_CIPHERTEXTBYTES];
+            let mut key = [0u8; CRYPTO_BYTES];
+            crypto_kem_enc_with_error_vector(&mut c, &mut key, pk, sub!(two_e, 1, SYS_N / 8))?;
+
+            // c = (syndrome, H(2, e)) and the shared key is H(1, e, c)
+            let mut syndrome = [0u8; CRYPTO_CIPHERTEXTBYTES];
+            encrypt(&mut syndrome, pk, sub!(two_e, 1, SYS_N / 8));
+            assert_eq!(c[..SYND_BYTES], syndrome[..SYND_BYTES]);
+
+            let mut confirmation = [0u8; 32];
+            shake256(&mut confirmation, &two_e);
+            assert_eq!(c[SYND_BYTES..], confirmation);
+
+            let one_ec = [&[1u8][..], &two_e[1..], &c].concat();
+            let mut expected = [0u8; CRYPTO_BYTES];
+            shake256(&mut expected, &one_ec);
+            assert_eq!(key, expected);
+
+            let mut key_dec = [0u8; CRYPTO_BYTES];
+            assert_eq!(crypto_kem_dec_with_status(&mut key_dec, &c, sk), Ok(0xFF));
+            assert_eq!(key_dec, key);
+
+            // a wrong confirmation is rejected implicitly, although the syndrome decodes: the key
+            // is H(0, s, c)
+            for i in [SYND_BYTES, CRYPTO_CIPHERTEXTBYTES - 1] {
+                let mut tampered = c;
+                tampered[i] ^= 0x10;
+                assert_eq!(
+                    crypto_kem_dec_with_status(&mut key_dec, &tampered, sk),
+                    Ok(0)
+                );
+
+                let s = &sk[40 + IRR_BYTES + COND_BYTES..];
+                let zero_sc = [&[0u8][..], s, &tampered].concat();
+                shake256(&mut expected, &zero_sc);
+                assert_eq!(key_dec, expected);
+            }
 
-        let entropy_input = <[u8; 48]>::try_from(
-            crate::TestData::new()
-                .u8vec("mceliece8192128f_operations_entropy_input")
-                .as_slice(),
-        )
-        .unwrap();
+            // the variant without plaintext confirmation shares the keys and the syndrome, but not
+            // the shared key
+            if CRYPTO_PRIMITIVE == "mceliece348864pc" {
+                use crate::mceliece348864 as non_pc;
+
+                let mut pk_non_pc = vec![0u8; non_pc::CRYPTO_PUBLICKEYBYTES];
+                let mut sk_non_pc = vec![0u8; non_pc::CRYPTO_SECRETKEYBYTES];
+                let pk_non_pc = sub!(mut pk_non_pc, 0, non_pc::CRYPTO_PUBLICKEYBYTES);
+                let sk_non_pc = sub!(mut sk_non_pc, 0, non_pc::CRYPTO_SECRETKEYBYTES);
+                non_pc::crypto_kem_keypair_from_seed(pk_non_pc, sk_non_pc, &[21u8; 32]);
+                assert_eq!(pk_non_pc[..], pk[..]);
+                assert_eq!(sk_non_pc[..], sk[..]);
+
+                let mut c_non_pc = [0u8; non_pc::CRYPTO_CIPHERTEXTBYTES];
+                let mut key_non_pc = [0u8; non_pc::CRYPTO_BYTES];
+                non_pc::crypto_kem_enc_with_error_vector(
+                    &mut c_non_pc,
+                    &mut key_non_pc,
+                    pk_non_pc,
+                    // the error vectors of both variants have the same length
+                    as_array(&two_e[1..])?,
+                )?;
+                assert_eq!(c_non_pc[..], c[..SYND_BYTES]);
+                assert_ne!(key_non_pc, key);
+            }
 
-        let compare_sk = crate::TestData::new().u8vec("mceliece8192128f_operations_sk_expected");
-        assert_eq!(compare_sk.len(), CRYPTO_SECRETKEYBYTES);
+            Ok(())
+        }
+    }
 
-        let compare_pk = crate::TestData::new().u8vec("mceliece8192128f_operations_pk_expected");
-        assert_eq!(compare_pk.len(), CRYPTO_PUBLICKEYBYTES);
+    variant_tests! {
+        [mceliece348864]
+
+        #[test]
+        #[cfg(not(feature = "alloc"))]
+        fn test_validate_secret_key_requires_alloc() {
+            // the seed is not expanded without `alloc`, regardless of the key
+            let sk = [0u8; CRYPTO_SECRETKEYBYTES];
+            assert_eq!(validate_secret_key(&sk, true), Err(Error::RequiresAlloc));
+            assert_eq!(
+                validate_secret_key(&sk[1..], true),
+                Err(Error::InvalidLength {
+                    expected: CRYPTO_SECRETKEYBYTES,
+                    actual: CRYPTO_SECRETKEYBYTES - 1
+                })
+            );
+        }
+    }
 
-        let mut rng_state = AesState::new();
-        rng_state.randombytes_init(entropy_input);
+    variant_tests! {
+        [mceliece460896]
+
+        #[test]
+        fn test_crypto_kem_keypair_from_seed() -> Result<(), Error> {
+            let mut pk = vec![0u8; CRYPTO_PUBLICKEYBYTES];
+            let mut sk = vec![0u8; CRYPTO_SECRETKEYBYTES];
+            let mut rng = AesState::new();
+            rng.randombytes_init([13u8; 48]);
+            crypto_kem_keypair(
+                sub!(mut pk, 0, CRYPTO_PUBLICKEYBYTES),
+                sub!(mut sk, 0, CRYPTO_SECRETKEYBYTES),
+                &mut rng,
+            )?;
+
+            // the RNG provides the seed with its first request
+            let mut seed = [0u8; 32];
+            rng.randombytes_init([13u8; 48]);
+            rng.randombytes(&mut seed)?;
+
+            let mut pk_from_seed = vec![0u8; CRYPTO_PUBLICKEYBYTES];
+            let mut sk_from_seed = vec![0u8; CRYPTO_SECRETKEYBYTES];
+            crypto_kem_keypair_from_seed(
+                sub!(mut pk_from_seed, 0, CRYPTO_PUBLICKEYBYTES),
+                sub!(mut sk_from_seed, 0, CRYPTO_SECRETKEYBYTES),
+                &seed,
+            );
+
+            assert_eq!(pk_from_seed, pk);
+            assert_eq!(sk_from_seed, sk);
+
+            Ok(())
+        }
+    }
 
-        crypto_kem_keypair(
-            sub!(mut pk_input, 0, CRYPTO_PUBLICKEYBYTES),
-            sub!(mut sk_input, 0, CRYPTO_SECRETKEYBYTES),
-            &mut rng_state,
-        )?;
+    variant_tests! {
+        [mceliece348864]
 
-        assert_eq!(compare_sk, sk_input);
-        assert_eq!(compare_pk, pk_input);
+        /// Asserts that every buffer of `names` was passed to `zeroize!` since the last call
+        fn assert_wiped(names: &[&str]) {
+            let wiped = crate::macros::take_wiped();
+            for name in names {
+                assert!(wiped.contains(name), "{} not wiped: {:?}", name, wiped);
+            }
+        }
 
-        Ok(())
+        #[test]
+        fn test_secret_buffers_wiped() -> Result<(), Error> {
+            let mut pk = vec![0u8; CRYPTO_PUBLICKEYBYTES];
+            let mut sk = vec![0u8; CRYPTO_SECRETKEYBYTES];
+            let pk = sub!(mut pk, 0, CRYPTO_PUBLICKEYBYTES);
+            let sk = sub!(mut sk, 0, CRYPTO_SECRETKEYBYTES);
+            let mut c = [0u8; CRYPTO_CIPHERTEXTBYTES];
+            let mut key = [0u8; CRYPTO_BYTES];
+
+            let mut rng = AesState::new();
+            rng.randombytes_init([14u8; 48]);
+            crate::macros::take_wiped();
+
+            crypto_kem_keypair(pk, sk, &mut rng)?;
+            // the seed and expanded randomness, the Goppa polynomial and support within `pk_gen`
+            assert_wiped(&[
+                "seed", "r", "f", "irr", "perm", "pi", "mat", "g", "l", "inv",
+            ]);
+
+            crypto_kem_enc(&mut c, &mut key, pk, &mut rng)?;
+            // the error vector, its candidates in `gen_e` and the preimage of the key
+            assert_wiped(&["two_e", "ind", "val", "bytes", "nums", "one_ec"]);
+
+            crypto_kem_dec(&mut key, &c, sk)?;
+            // the decoded error vector, the preimage of the key and the decoder state of `decrypt`
+            assert_wiped(&[
+                "two_e", "preimage", "conf", "r", "g", "l", "locator", "images",
+            ]);
+
+            // failing RNG requests return early, the buffers are wiped nevertheless
+            let mut unseeded = AesState::new();
+            assert_eq!(
+                crypto_kem_keypair(pk, sk, &mut unseeded),
+                Err(Error::UnseededRng)
+            );
+            assert_wiped(&["seed"]);
+
+            assert_eq!(
+                crypto_kem_enc(&mut c, &mut key, pk, &mut unseeded),
+                Err(Error::UnseededRng)
+            );
+            assert_wiped(&["ind", "val", "bytes", "nums", "two_e"]);
+
+            #[cfg(feature = "std")]
+            {
+                assert_eq!(
+                    crypto_kem_enc_from_reader(&mut c, &mut key, &pk[..], &mut unseeded).err(),
+                    Some(Error::UnseededRng)
+                );
+                assert_wiped(&["bytes", "two_e"]);
+            }
+
+            Ok(())
+        }
+    }
+
+    variant_tests! {
+        [mceliece6960119, mceliece6960119f, mceliece6960119pc, mceliece6960119pcf]
+
+        #[test]
+        fn test_padding_outcome() {
+            let mut rng = AesState::new();
+            rng.randombytes_init([9u8; 48]);
+
+            let mut pk = vec![0u8; CRYPTO_PUBLICKEYBYTES];
+            pk[PK_ROW_BYTES - 1] = 0x80;
+            let mut c = [0xAAu8; CRYPTO_CIPHERTEXTBYTES];
+            let mut key = [0xAAu8; CRYPTO_BYTES];
+            let result = crypto_kem_enc(
+                &mut c,
+                &mut key,
+                sub!(pk, 0, CRYPTO_PUBLICKEYBYTES),
+                &mut rng,
+            );
+            assert_eq!(result, Err(Error::InvalidPadding));
+            assert_eq!(c, [0u8; CRYPTO_CIPHERTEXTBYTES]);
+            assert_eq!(key, [0u8; CRYPTO_BYTES]);
+
+            let sk = vec![0u8; CRYPTO_SECRETKEYBYTES];
+            c[SYND_BYTES - 1] = 0x80;
+            let result = crypto_kem_dec(&mut key, &c, sub!(sk, 0, CRYPTO_SECRETKEYBYTES));
+            assert_eq!(result, Err(Error::InvalidPadding));
+            assert_eq!(key, [0xFFu8; CRYPTO_BYTES]);
+        }
+    }
+
+    variant_tests! {
+        [mceliece6960119]
+
+        // the key generation of `mceliece6960119` does not fit on the stack of the test threads
+        // without `alloc`
+        #[test]
+        #[cfg(feature = "alloc")]
+        fn test_crypto_kem_dec_tampered_padding() -> Result<(), Error> {
+            let mut pk = vec![0u8; CRYPTO_PUBLICKEYBYTES];
+            let mut sk = vec![0u8; CRYPTO_SECRETKEYBYTES];
+            let pk = sub!(mut pk, 0, CRYPTO_PUBLICKEYBYTES);
+            let sk = sub!(mut sk, 0, CRYPTO_SECRETKEYBYTES);
+            crypto_kem_keypair_from_seed(pk, sk, &[5u8; 32]);
+
+            let mut rng = AesState::new();
+            rng.randombytes_init([5u8; 48]);
+            let mut c = [0u8; CRYPTO_CIPHERTEXTBYTES];
+            let mut ss = [0u8; CRYPTO_BYTES];
+            crypto_kem_enc(&mut c, &mut ss, pk, &mut rng)?;
+
+            // the non-pc ciphertext is the bare syndrome, its last byte holds the padding bits
+            assert_eq!(CRYPTO_CIPHERTEXTBYTES, SYND_BYTES);
+            let mut ss_dec = [0u8; CRYPTO_BYTES];
+            crypto_kem_dec(&mut ss_dec, &c, sk)?;
+            assert_eq!(ss_dec, ss);
+
+            c[SYND_BYTES - 1] |= 0x80;
+            let mut ss_dec = [0xAAu8; CRYPTO_BYTES];
+            assert_eq!(
+                crypto_kem_dec(&mut ss_dec, &c, sk),
+                Err(Error::InvalidPadding)
+            );
+            assert_eq!(ss_dec, [0xFFu8; CRYPTO_BYTES]);
+
+            Ok(())
+        }
+    }
+
+    variant_tests! {
+        [mceliece348864]
+
+        // the first test case of the response file generated by `katkem`: the ciphertext equals
+        // the syndrome of the round-3 reference ciphertext, while the shared secret has not yet
+        // been compared with the round-4 reference implementation
+        #[test]
+        fn test_crypto_kem_known_answer() -> Result<(), Error> {
+            let seed = [
+                0x06, 0x15, 0x50, 0x23, 0x4D, 0x15, 0x8C, 0x5E, 0xC9, 0x55, 0x95, 0xFE, 0x04, 0xEF,
+                0x7A, 0x25, 0x76, 0x7F, 0x2E, 0x24, 0xCC, 0x2B, 0xC4, 0x79, 0xD0, 0x9D, 0x86, 0xDC,
+                0x9A, 0xBC, 0xFD, 0xE7, 0x05, 0x6A, 0x8C, 0x26, 0x6F, 0x9E, 0xF9, 0x7E, 0xD0, 0x85,
+                0x41, 0xDB, 0xD2, 0xE1, 0xFF, 0xA1,
+            ];
+            let expected_ct = [
+                0xDE, 0xF6, 0x19, 0x08, 0xA7, 0x0A, 0x30, 0x99, 0xE4, 0x5B, 0x4D, 0x5D, 0x91, 0x95,
+                0x7A, 0xDE, 0x70, 0xF5, 0x71, 0xD2, 0x10, 0xD5, 0x25, 0xD6, 0x55, 0xDB, 0x72, 0x94,
+                0x51, 0x5F, 0x91, 0xD9, 0x77, 0x95, 0xF2, 0x35, 0x36, 0x15, 0xBC, 0x7C, 0xDF, 0x13,
+                0x50, 0x21, 0x81, 0xE5, 0xBC, 0xC8, 0xC9, 0xAB, 0xFE, 0xF3, 0x18, 0x19, 0xD6, 0x6D,
+                0xD2, 0x76, 0x03, 0x63, 0x69, 0x4F, 0x78, 0x96, 0x02, 0x26, 0x4A, 0x3E, 0x24, 0x44,
+                0x56, 0x81, 0xA0, 0x18, 0x3C, 0xE3, 0x43, 0xA2, 0x26, 0x4F, 0xDF, 0xF9, 0x6C, 0x82,
+                0xAB, 0x31, 0x8A, 0xE8, 0x88, 0xD1, 0x05, 0xD5, 0x2D, 0x59, 0xBC, 0x1B,
+            ];
+            let expected_ss = [
+                0xB4, 0xF9, 0xFF, 0x1E, 0x43, 0x90, 0xE3, 0xBE, 0x0B, 0xBC, 0xEB, 0xFF, 0x9A, 0x52,
+                0x5A, 0xE8, 0x3B, 0x19, 0x12, 0x11, 0x89, 0x6A, 0xA8, 0x78, 0x6C, 0xE8, 0xBC, 0x51,
+                0x1C, 0x9F, 0x78, 0xC3,
+            ];
+
+            let mut pk = vec![0u8; CRYPTO_PUBLICKEYBYTES];
+            let mut sk = vec![0u8; CRYPTO_SECRETKEYBYTES];
+            let pk = sub!(mut pk, 0, CRYPTO_PUBLICKEYBYTES);
+            let sk = sub!(mut sk, 0, CRYPTO_SECRETKEYBYTES);
+
+            let mut rng = AesState::new();
+            rng.randombytes_init(seed);
+            crypto_kem_keypair(pk, sk, &mut rng)?;
+
+            let mut c = [0u8; CRYPTO_CIPHERTEXTBYTES];
+            let mut ss = [0u8; CRYPTO_BYTES];
+            crypto_kem_enc(&mut c, &mut ss, pk, &mut rng)?;
+            assert_eq!(c, expected_ct);
+            assert_eq!(ss, expected_ss);
+
+            let mut ss_dec = [0u8; CRYPTO_BYTES];
+            crypto_kem_dec(&mut ss_dec, &c, sk)?;
+            assert_eq!(ss_dec, expected_ss);
+
+            Ok(())
+        }
+    }
+
+    variant_tests! {
+        [mceliece8192128pcf]
+
+        // the test data originates from round 3, which corresponds to the `pc` variants
+        #[test]
+        fn test_crypto_kem_dec() -> Result<(), Error> {
+            let mut sk = crate::TestData::new().u8vec("mceliece8192128f_sk1");
+            let mut c = crate::TestData::new().u8vec("mceliece8192128f_ct1");
+            let mut test_key = [0u8; 32];
+            let compare_key = crate::TestData::new().u8vec("mceliece8192128f_operations_ss");
+
+            crypto_kem_dec(
+                &mut test_key,
+                sub!(mut c, 0, CRYPTO_CIPHERTEXTBYTES),
+                sub!(mut sk, 0, CRYPTO_SECRETKEYBYTES),
+            )?;
+
+            assert_eq!(test_key, compare_key.as_slice());
+
+            Ok(())
+        }
+
+        // the test data originates from round 3, which corresponds to the `pc` variants
+        #[test]
+        fn test_crypto_kem_enc() -> Result<(), Error> {
+            let mut c = [0u8; CRYPTO_CIPHERTEXTBYTES];
+            let mut ss = [0u8; CRYPTO_BYTES];
+            let mut pk = crate::TestData::new().u8vec("mceliece8192128f_pk1");
+            assert_eq!(pk.len(), CRYPTO_PUBLICKEYBYTES);
+
+            let compare_ss = crate::TestData::new().u8vec("mceliece8192128f_operations_ss");
+            let compare_ct = crate::TestData::new().u8vec("mceliece8192128f_operations_enc1_ct");
+
+            // set the same seed as in C implementation
+            let entropy_input = <[u8; 48]>::try_from(
+                crate::TestData::new()
+                    .u8vec("mceliece8192128f_operations_entropy_input")
+                    .as_slice(),
+            )
+            .unwrap();
+
+            let mut rng_state = AesState::new();
+            rng_state.randombytes_init(entropy_input);
+
+            let mut second_seed = [0u8; 33];
+            second_seed[0] = 64;
+
+            rng_state.randombytes(&mut second_seed[1..])?;
+
+            crypto_kem_enc(
+                &mut c,
+                &mut ss,
+                sub!(mut pk, 0, CRYPTO_PUBLICKEYBYTES),
+                &mut rng_state,
+            )?;
+
+            assert_eq!(ss, compare_ss.as_slice());
+
+            assert_eq!(c, compare_ct.as_slice());
+
+            Ok(())
+        }
+    }
+
+    variant_tests! {
+        [mceliece8192128f]
+
+        #[test]
+        fn test_crypto_kem_keypair() -> Result<(), Error> {
+            let mut pk_input = [0; CRYPTO_PUBLICKEYBYTES].to_vec();
+            let mut sk_input = [0; CRYPTO_SECRETKEYBYTES].to_vec();
+
+            let entropy_input = <[u8; 48]>::try_from(
+                crate::TestData::new()
+                    .u8vec("mceliece8192128f_operations_entropy_input")
+                    .as_slice(),
+            )
+            .unwrap();
+
+            let compare_sk =
+                crate::TestData::new().u8vec("mceliece8192128f_operations_sk_expected");
+            assert_eq!(compare_sk.len(), CRYPTO_SECRETKEYBYTES);
+
+            let compare_pk =
+                crate::TestData::new().u8vec("mceliece8192128f_operations_pk_expected");
+            assert_eq!(compare_pk.len(), CRYPTO_PUBLICKEYBYTES);
+
+            let mut rng_state = AesState::new();
+            rng_state.randombytes_init(entropy_input);
+
+            crypto_kem_keypair(
+                sub!(mut pk_input, 0, CRYPTO_PUBLICKEYBYTES),
+                sub!(mut sk_input, 0, CRYPTO_SECRETKEYBYTES),
+                &mut rng_state,
+            )?;
+
+            assert_eq!(compare_sk, sk_input);
+            assert_eq!(compare_pk, pk_input);
+
+            Ok(())
+        }
     }
 }
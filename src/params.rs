@@ -1,44 +1,42 @@
 //! Global paramaters for the different Classic McEliece variants
+//!
+//! The primary parameters are defined by the variant module this module is part of.
 
-#[cfg(any(feature = "mceliece348864", feature = "mceliece348864f"))]
-pub const GFBITS: usize = 12;
-#[cfg(any(feature = "mceliece348864", feature = "mceliece348864f"))]
-pub const SYS_N: usize = 3488;
-#[cfg(any(feature = "mceliece348864", feature = "mceliece348864f"))]
-pub const SYS_T: usize = 64;
+pub const GFBITS: usize = super::GFBITS;
+pub const SYS_N: usize = super::SYS_N;
+pub const SYS_T: usize = super::SYS_T;
 
-#[cfg(any(feature = "mceliece460896", feature = "mceliece460896f"))]
-pub const GFBITS: usize = 13;
-#[cfg(any(feature = "mceliece460896", feature = "mceliece460896f"))]
-pub const SYS_N: usize = 4608;
-#[cfg(any(feature = "mceliece460896", feature = "mceliece460896f"))]
-pub const SYS_T: usize = 96;
-
-#[cfg(any(feature = "mceliece6688128", feature = "mceliece6688128f"))]
-pub const GFBITS: usize = 13;
-#[cfg(any(feature = "mceliece6688128", feature = "mceliece6688128f"))]
-pub const SYS_N: usize = 6688;
-#[cfg(any(feature = "mceliece6688128", feature = "mceliece6688128f"))]
-pub const SYS_T: usize = 128;
-
-#[cfg(any(feature = "mceliece6960119", feature = "mceliece6960119f"))]
-pub const GFBITS: usize = 13;
-#[cfg(any(feature = "mceliece6960119", feature = "mceliece6960119f"))]
-pub const SYS_N: usize = 6960;
-#[cfg(any(feature = "mceliece6960119", feature = "mceliece6960119f"))]
-pub const SYS_T: usize = 119;
-
-#[cfg(any(feature = "mceliece8192128", feature = "mceliece8192128f"))]
-pub const GFBITS: usize = 13;
-#[cfg(any(feature = "mceliece8192128", feature = "mceliece8192128f"))]
-pub const SYS_N: usize = 8192;
-#[cfg(any(feature = "mceliece8192128", feature = "mceliece8192128f"))]
-pub const SYS_T: usize = 128;
+/// Is the public key generated through the semi-systematic form (`f` variants)?
+pub const SEMI_SYSTEMATIC: bool = super::SEMI_SYSTEMATIC;
+/// Does the ciphertext carry the 32-byte confirmation hash of the error vector (`pc` variants)?
+pub const PLAINTEXT_CONFIRMATION: bool = super::PLAINTEXT_CONFIRMATION;
+/// Non-leading terms of the polynomial defining GF((2^m)^t) as (degree, coefficient)
+pub const FIELD_POLY_TERMS: &[(usize, u16)] = super::FIELD_POLY_TERMS;
 
 pub const COND_BYTES: usize = (1 << (GFBITS - 4)) * (2 * GFBITS - 1);
 pub const IRR_BYTES: usize = SYS_T * 2;
 pub const PK_NROWS: usize = SYS_T * GFBITS;
 pub const PK_NCOLS: usize = SYS_N - PK_NROWS;
-pub const PK_ROW_BYTES: usize = (PK_NCOLS + 7) / 8;
-pub const SYND_BYTES: usize = (PK_NROWS + 7) / 8;
+pub const PK_ROW_BYTES: usize = PK_NCOLS.div_ceil(8);
+pub const SYND_BYTES: usize = PK_NROWS.div_ceil(8);
 pub const GFMASK: usize = (1 << GFBITS) - 1;
+
+#[cfg(test)]
+mod tests {
+    use super::super::{CRYPTO_CIPHERTEXTBYTES, CRYPTO_PUBLICKEYBYTES, CRYPTO_SECRETKEYBYTES};
+    use super::*;
+
+    #[test]
+    fn test_api_lengths() {
+        assert_eq!(CRYPTO_PUBLICKEYBYTES, PK_NROWS * PK_ROW_BYTES);
+        assert_eq!(
+            CRYPTO_SECRETKEYBYTES,
+            40 + IRR_BYTES + COND_BYTES + SYS_N / 8
+        );
+        if PLAINTEXT_CONFIRMATION {
+            assert_eq!(CRYPTO_CIPHERTEXTBYTES, SYND_BYTES + 32);
+        } else {
+            assert_eq!(CRYPTO_CIPHERTEXTBYTES, SYND_BYTES);
+        }
+    }
+}
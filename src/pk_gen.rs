@@ -1,32 +1,17 @@
 //! Generation of public key
 
-use crate::{
+use super::{
     gf::{gf_inv, gf_mul},
-    macros::sub,
-    params::{GFBITS, GFMASK, PK_NROWS, PK_ROW_BYTES, SYS_N, SYS_T},
+    params::{GFBITS, GFMASK, PK_NROWS, PK_ROW_BYTES, SEMI_SYSTEMATIC, SYS_N, SYS_T},
     root::root,
+    util::{bitrev, large_array, load8, load_gf, store8, LargeArray},
+};
+use crate::{
+    macros::{sub, zeroize},
     uint64_sort::uint64_sort,
-    util::{bitrev, load_gf},
 };
-use std::error;
-
-#[cfg(any(
-    feature = "mceliece348864f",
-    feature = "mceliece460896f",
-    feature = "mceliece6688128f",
-    feature = "mceliece6960119f",
-    feature = "mceliece8192128f"
-))]
-use crate::util::{load8, store8};
 
 /// Return number of trailing zeros of the non-zero input `input`
-#[cfg(any(
-    feature = "mceliece348864f",
-    feature = "mceliece460896f",
-    feature = "mceliece6688128f",
-    feature = "mceliece6960119f",
-    feature = "mceliece8192128f"
-))]
 fn ctz(input: u64) -> i32 {
     let (mut m, mut r) = (0i32, 0i32);
 
@@ -40,13 +25,6 @@ fn ctz(input: u64) -> i32 {
 }
 
 /// Takes two 16-bit integers and determines whether they are equal (u64::MAX) or different (0)
-#[cfg(any(
-    feature = "mceliece348864f",
-    feature = "mceliece460896f",
-    feature = "mceliece6688128f",
-    feature = "mceliece6960119f",
-    feature = "mceliece8192128f"
-))]
 fn same_mask(x: u16, y: u16) -> u64 {
     let mut mask = (x ^ y) as u64;
     mask = mask.wrapping_sub(1);
@@ -57,44 +35,34 @@ fn same_mask(x: u16, y: u16) -> u64 {
 }
 
 /// Move columns in matrix `mat`
-#[cfg(any(
-    feature = "mceliece348864f",
-    feature = "mceliece460896f",
-    feature = "mceliece6688128f",
-    feature = "mceliece6960119f",
-    feature = "mceliece8192128f"
-))]
+#[allow(clippy::identity_op, clippy::needless_range_loop)]
 fn mov_columns(
     mat: &mut [[u8; SYS_N / 8]; PK_NROWS],
     pi: &mut [i16; 1 << GFBITS],
     pivots: &mut u64,
-) -> Result<i32, Box<dyn error::Error>> {
+) -> i32 {
     let mut buf = [0u64; 64];
     let mut ctz_list = [0u64; 32];
 
     let row = PK_NROWS - 32;
     let block_idx = row / 8;
 
-    #[cfg(feature = "mceliece6960119f")]
     let tail = row % 8;
-    #[cfg(feature = "mceliece6960119f")]
     let mut tmp = [0u8; 9];
 
-    #[cfg(not(feature = "mceliece6960119f"))]
-    for i in 0..32 {
-        buf[i] = load8(sub!(mat[row + i], block_idx, 8));
-    }
-
-    #[cfg(feature = "mceliece6960119f")]
-    for i in 0..32 {
-        for j in 0..9 {
-            tmp[j] = mat[row + i][block_idx + j];
-        }
-        for j in 0..8 {
-            tmp[j] = (tmp[j] >> tail) | (tmp[j + 1] << (8 - tail));
+    if tail == 0 {
+        for i in 0..32 {
+            buf[i] = load8(sub!(mat[row + i], block_idx, 8));
         }
+    } else {
+        for i in 0..32 {
+            tmp.copy_from_slice(&mat[row + i][block_idx..block_idx + 9]);
+            for j in 0..8 {
+                tmp[j] = (tmp[j] >> tail) | (tmp[j + 1] << (8 - tail));
+            }
 
-        buf[i] = load8(sub!(tmp, 0, 8));
+            buf[i] = load8(sub!(tmp, 0, 8));
+        }
     }
 
     // Compute the column indices of pivots by Gaussian elimination.
@@ -108,7 +76,8 @@ fn mov_columns(
         }
 
         if t == 0 {
-            return Ok(-1); // return if buf is not full rank
+            zeroize!(buf, ctz_list, tmp);
+            return -1; // return if buf is not full rank
         }
 
         ctz_list[i] = ctz(t) as u64;
@@ -140,58 +109,94 @@ fn mov_columns(
     }
 
     // moving columns of mat according to the column indices of pivots
-    #[cfg(not(feature = "mceliece6960119f"))]
-    for i in 0..PK_NROWS {
-        let mut t = load8(sub!(mat[i], block_idx, 8));
+    if tail == 0 {
+        for i in 0..PK_NROWS {
+            let mut t = load8(sub!(mat[i], block_idx, 8));
+
+            for j in 0..32 {
+                let mut d: u64 = t >> j;
+                d ^= t >> ctz_list[j];
+                d &= 1;
 
-        for j in 0..32 {
-            let mut d: u64 = t >> j;
-            d ^= t >> ctz_list[j];
-            d &= 1;
+                t ^= d << ctz_list[j];
+                t ^= d << j;
+            }
 
-            t ^= d << ctz_list[j];
-            t ^= d << j;
+            store8(sub!(mut mat[i], block_idx, 8), t);
         }
+    } else {
+        for i in 0..PK_NROWS {
+            tmp.copy_from_slice(&mat[i][block_idx..block_idx + 9]);
+            for k in 0..8 {
+                tmp[k] = (tmp[k] >> tail) | (tmp[k + 1] << (8 - tail));
+            }
 
-        store8(sub!(mut mat[i], block_idx, 8), t);
-    }
+            let mut t = load8(sub!(tmp, 0, 8));
 
-    #[cfg(feature = "mceliece6960119f")]
-    for i in 0..PK_NROWS {
-        for k in 0..9 {
-            tmp[k] = mat[i][block_idx + k];
-        }
-        for k in 0..8 {
-            tmp[k] = (tmp[k] >> tail) | (tmp[k + 1] << (8 - tail));
-        }
+            for j in 0..32 {
+                let mut d = t >> j;
+                d ^= t >> ctz_list[j];
+                d &= 1;
 
-        let mut t = load8(sub!(tmp, 0, 8));
+                t ^= d << ctz_list[j];
+                t ^= d << j;
+            }
 
-        for j in 0..32 {
-            let mut d = t >> j;
-            d ^= t >> ctz_list[j];
-            d &= 1;
+            store8(sub!(mut tmp, 0, 8), t);
 
-            t ^= d << ctz_list[j];
-            t ^= d << j;
+            mat[i][block_idx + 8] =
+                (mat[i][block_idx + 8] >> tail << tail) | (tmp[7] >> (8 - tail));
+            mat[i][block_idx + 0] =
+                (tmp[0] << tail) | (mat[i][block_idx] << (8 - tail) >> (8 - tail));
+
+            for k in (1..=7).rev() {
+                mat[i][block_idx + k] = (tmp[k] << tail) | (tmp[k - 1] >> (8 - tail));
+            }
         }
+    }
+
+    zeroize!(buf, ctz_list, tmp);
 
-        store8(sub!(mut tmp, 0, 8), t);
+    0
+}
 
-        mat[i][block_idx + 8] = (mat[i][block_idx + 8] >> tail << tail) | (tmp[7] >> (8 - tail));
-        mat[i][block_idx + 0] = (tmp[0] << tail) | (mat[i][block_idx] << (8 - tail) >> (8 - tail));
+/// Revert the permutation of `pi` by `mov_columns`, which returned the pivot positions `pivots`.
+/// Returns -1 if `pivots` does not contain exactly 32 positions.
+#[allow(clippy::needless_range_loop)]
+pub(crate) fn restore_columns(pi: &mut [i16; 1 << GFBITS], pivots: u64) -> i32 {
+    if pivots.count_ones() != 32 {
+        return -1;
+    }
 
-        for k in (1..=7).rev() {
-            mat[i][block_idx + k] = (tmp[k] << tail) | (tmp[k - 1] >> (8 - tail));
+    // the pivot positions are found in increasing order by `mov_columns`
+    let mut ctz_list = [0u64; 32];
+    let mut t = pivots;
+    for j in 0..32 {
+        ctz_list[j] = ctz(t) as u64;
+        t &= t.wrapping_sub(1);
+    }
+
+    let row = PK_NROWS - 32;
+
+    // the swaps are undone in reverse order
+    for j in (0..32).rev() {
+        for k in j + 1..64 {
+            let mut d = (pi[row + j] ^ pi[row + k]) as u64;
+            d &= same_mask(k as u16, ctz_list[j] as u16);
+            pi[row + j] ^= d as i16;
+            pi[row + k] ^= d as i16;
         }
     }
 
-    Ok(0)
+    zeroize!(ctz_list, t);
+
+    0
 }
 
 /// Public key generation. Generate the public key `pk`,
 /// permutation `pi` and pivot element `pivots` based on the
 /// secret key `sk` and permutation `perm` provided.
+/// `pivots` is only updated by the semi-systematic (`f`) variants.
 /// `pk` has `max(1 << GFBITS, SYS_N)` elements which is
 /// 4096 for mceliece348864 and 8192 for mceliece8192128.
 /// `sk` has `2 * SYS_T` elements and perm `1 << GFBITS`.
@@ -200,26 +205,9 @@ pub(crate) fn pk_gen(
     sk: &[u8; 2 * SYS_T],
     perm: &[u32; 1 << GFBITS],
     pi: &mut [i16; 1 << GFBITS],
-    #[cfg(any(
-        feature = "mceliece348864f",
-        feature = "mceliece460896f",
-        feature = "mceliece6688128f",
-        feature = "mceliece6960119f",
-        feature = "mceliece8192128f"
-    ))]
     pivots: &mut u64,
-) -> Result<i32, Box<dyn error::Error>> {
-    let mut buf = [0u64; 1 << GFBITS];
-    let mut mat = [[0u8; SYS_N / 8]; PK_NROWS];
-
-    let mut g = [0u16; SYS_T + 1];
-    let mut l = [0u16; SYS_N];
-    let mut inv = [0u16; SYS_N];
-
-    g[SYS_T] = 1;
-    for (i, chunk) in sk.chunks(2).take(SYS_T).enumerate() {
-        g[i] = load_gf(sub!(chunk, 0, 2));
-    }
+) -> i32 {
+    let mut buf: LargeArray<u64, { 1 << GFBITS }> = large_array(0u64);
 
     for i in 0..(1 << GFBITS) {
         buf[i] = perm[i] as u64;
@@ -231,7 +219,8 @@ pub(crate) fn pk_gen(
 
     for i in 1..(1 << GFBITS) {
         if buf[i - 1] >> 31 == buf[i] >> 31 {
-            return Ok(-1);
+            zeroize!(buf);
+            return -1;
         }
     }
 
@@ -239,6 +228,34 @@ pub(crate) fn pk_gen(
         pi[i] = buf[i] as i16 & GFMASK as i16;
     }
 
+    zeroize!(buf);
+
+    pk_gen_from_permutation(pk, sk, pi, pivots)
+}
+
+/// Second part of the public key generation, which computes the public key `pk`
+/// for the Goppa polynomial stored in `sk` and the support given by the permutation `pi`.
+/// Only the first `SYS_N` elements of `pi` are used. The semi-systematic (`f`) variants
+/// update `pi` and `pivots` like `pk_gen`.
+#[allow(clippy::identity_op, clippy::needless_range_loop)]
+pub(crate) fn pk_gen_from_permutation(
+    pk: &mut [u8; PK_NROWS * PK_ROW_BYTES],
+    sk: &[u8; 2 * SYS_T],
+    pi: &mut [i16; 1 << GFBITS],
+    pivots: &mut u64,
+) -> i32 {
+    // the matrix takes more than 1 MB for the larger variants, hence it lives on the heap
+    let mut mat: LargeArray<[u8; SYS_N / 8], PK_NROWS> = large_array([0u8; SYS_N / 8]);
+
+    let mut g = [0u16; SYS_T + 1];
+    let mut l = [0u16; SYS_N];
+    let mut inv = [0u16; SYS_N];
+
+    g[SYS_T] = 1;
+    for (i, chunk) in sk.chunks(2).take(SYS_T).enumerate() {
+        g[i] = load_gf(sub!(chunk, 0, 2));
+    }
+
     for i in 0..SYS_N {
         l[i] = bitrev(pi[i] as u16);
     }
@@ -276,7 +293,7 @@ pub(crate) fn pk_gen(
         }
     }
     // gaussian elimination
-    const ROWS: usize = (PK_NROWS + 7) / 8;
+    const ROWS: usize = PK_NROWS.div_ceil(8);
 
     for i in 0..ROWS {
         for j in 0..8 {
@@ -288,19 +305,9 @@ pub(crate) fn pk_gen(
                 break;
             }
 
-            #[cfg(any(
-                feature = "mceliece348864f",
-                feature = "mceliece460896f",
-                feature = "mceliece6688128f",
-                feature = "mceliece6960119f",
-                feature = "mceliece8192128f"
-            ))]
-            {
-                if row == PK_NROWS - 32 {
-                    if mov_columns(&mut mat, pi, pivots)? != 0 {
-                        return Ok(-1);
-                    }
-                }
+            if SEMI_SYSTEMATIC && row == PK_NROWS - 32 && mov_columns(&mut mat, pi, pivots) != 0 {
+                zeroize!(mat, g, l, inv);
+                return -1;
             }
 
             for k in (row + 1)..PK_NROWS {
@@ -315,7 +322,8 @@ pub(crate) fn pk_gen(
             }
 
             if ((mat[row][i] >> j) & 1) == 0 {
-                return Ok(-1);
+                zeroize!(mat, g, l, inv);
+                return -1;
             }
 
             for k in 0..PK_NROWS {
@@ -334,50 +342,33 @@ pub(crate) fn pk_gen(
         }
     }
 
-    #[cfg(any(feature = "mceliece6960119", feature = "mceliece6960119f"))]
     let tail = PK_NROWS % 8;
-    #[cfg(any(feature = "mceliece6960119", feature = "mceliece6960119f"))]
     const INNER_PK_ACCESSES: usize = ((SYS_N / 8 - 1) - (PK_NROWS - 1) / 8) + 1;
 
     for i in 0..PK_NROWS {
-        #[cfg(not(any(feature = "mceliece6960119", feature = "mceliece6960119f")))]
-        pk[i * PK_ROW_BYTES..(i + 1) * PK_ROW_BYTES]
-            .copy_from_slice(&mat[i][PK_NROWS / 8..PK_NROWS / 8 + PK_ROW_BYTES]);
-
-        #[cfg(any(feature = "mceliece6960119", feature = "mceliece6960119f"))]
-        for (idx, j) in ((PK_NROWS - 1) / 8..SYS_N / 8 - 1).enumerate() {
-            pk[i * INNER_PK_ACCESSES + idx] = (mat[i][j] >> tail) | (mat[i][j + 1] << (8 - tail));
-        }
-        #[cfg(any(feature = "mceliece6960119", feature = "mceliece6960119f"))]
-        {
+        if tail == 0 {
+            pk[i * PK_ROW_BYTES..(i + 1) * PK_ROW_BYTES]
+                .copy_from_slice(&mat[i][PK_NROWS / 8..PK_NROWS / 8 + PK_ROW_BYTES]);
+        } else {
+            for (idx, j) in ((PK_NROWS - 1) / 8..SYS_N / 8 - 1).enumerate() {
+                pk[i * INNER_PK_ACCESSES + idx] =
+                    (mat[i][j] >> tail) | (mat[i][j + 1] << (8 - tail));
+            }
             pk[(i + 1) * INNER_PK_ACCESSES - 1] = mat[i][SYS_N / 8 - 1] >> tail;
         }
     }
 
-    Ok(0)
+    zeroize!(mat, g, l, inv);
+
+    0
 }
 
 #[cfg(test)]
 mod tests {
-    #[cfg(any(
-        feature = "mceliece348864f",
-        feature = "mceliece460896f",
-        feature = "mceliece6688128f",
-        feature = "mceliece6960119f",
-        feature = "mceliece8192128f"
-    ))]
     use super::*;
-    #[cfg(feature = "mceliece8192128f")]
-    use crate::api::CRYPTO_PUBLICKEYBYTES;
 
     #[test]
-    #[cfg(any(
-        feature = "mceliece348864f",
-        feature = "mceliece460896f",
-        feature = "mceliece6688128f",
-        feature = "mceliece6960119f",
-        feature = "mceliece8192128f"
-    ))]
+    #[allow(clippy::needless_range_loop)]
     fn test_ctz() {
         const EXPECTED: [i32; 180] = [
             64, 0, 1, 0, 2, 0, 1, 0, 3, 0, 1, 0, 2, 0, 1, 0, 4, 0, 1, 0, 2, 0, 1, 0, 3, 0, 1, 0, 2,
@@ -394,13 +385,6 @@ mod tests {
     }
 
     #[test]
-    #[cfg(any(
-        feature = "mceliece348864f",
-        feature = "mceliece460896f",
-        feature = "mceliece6688128f",
-        feature = "mceliece6960119f",
-        feature = "mceliece8192128f"
-    ))]
     fn test_same_mask() {
         const EXPECTED: [u64; 25] = [
             0xFFFFFFFFFFFFFFFF,
@@ -436,143 +420,142 @@ mod tests {
         }
     }
 
-    #[test]
-    #[cfg(feature = "mceliece8192128f")]
-    fn test_mov_columns() -> Result<(), Box<dyn error::Error>> {
-        const COLS: usize = SYS_N / 8;
-
-        // input data
-        let mut mat = vec![[0u8; COLS]; PK_NROWS];
-        let mat_data = crate::TestData::new().u8vec("mceliece8192128f_mat_before");
-        assert_eq!(mat_data.len(), PK_NROWS * COLS);
-
-        for row in 0..PK_NROWS {
-            for col in 0..COLS {
-                mat[row][col] = mat_data[row * COLS + col];
-            }
-        }
+    variant_tests! {
+        [mceliece8192128f]
 
-        let mut pi = crate::TestData::new().i16vec("mceliece8192128f_pi_before");
-        let mut pivots = 0u64;
+        use super::super::CRYPTO_PUBLICKEYBYTES;
 
-        // generated actual result
-        mov_columns(
-            sub!(mut mat.as_mut_slice(), 0, PK_NROWS, [u8; COLS]),
-            sub!(mut pi, 0, 1 << GFBITS, i16),
-            &mut pivots,
-        )?;
+        #[test]
+        fn test_mov_columns() {
+            const COLS: usize = SYS_N / 8;
 
-        // expected data
-        let mut mat_expected = [[0u8; COLS]; PK_NROWS];
-        let mat_expected_data = crate::TestData::new().u8vec("mceliece8192128f_mat_expected");
+            // input data
+            let mut mat = vec![[0u8; COLS]; PK_NROWS];
+            let mat_data = crate::TestData::new().u8vec("mceliece8192128f_mat_before");
+            assert_eq!(mat_data.len(), PK_NROWS * COLS);
 
-        for row in 0..PK_NROWS {
-            mat_expected[row].copy_from_slice(&mat_expected_data[row * COLS..(row + 1) * COLS]);
-        }
+            for row in 0..PK_NROWS {
+                for col in 0..COLS {
+                    mat[row][col] = mat_data[row * COLS + col];
+                }
+            }
 
-        let pi_expected = crate::TestData::new().i16vec("mceliece8192128f_pi_expected");
-        let pivots_expected = 8053063679u64;
+            let mut pi = crate::TestData::new().i16vec("mceliece8192128f_pi_before");
+            let mut pivots = 0u64;
 
-        // comparison
-        assert_eq!(mat, mat_expected);
-        assert_eq!(pi, pi_expected);
-        assert_eq!(pivots, pivots_expected);
+            // generated actual result
+            mov_columns(
+                sub!(mut mat.as_mut_slice(), 0, PK_NROWS, [u8; COLS]),
+                sub!(mut pi, 0, 1 << GFBITS, i16),
+                &mut pivots,
+            );
 
-        Ok(())
-    }
+            // expected data
+            let mut mat_expected = [[0u8; COLS]; PK_NROWS];
+            let mat_expected_data = crate::TestData::new().u8vec("mceliece8192128f_mat_expected");
 
-    #[test]
-    #[cfg(feature = "mceliece8192128f")]
-    fn test_pk_gen_1() -> Result<(), Box<dyn error::Error>> {
-        let sk_data = crate::TestData::new().u8vec("mceliece8192128f_pk_gen_sk_input");
-        let perm_data = crate::TestData::new().u32vec("mceliece8192128f_pk_gen_perm_input");
-
-        let mut pk = vec![0u8; CRYPTO_PUBLICKEYBYTES];
-        let mut sk = [0u8; 2 * SYS_T];
-        let mut perm = [0u32; 1 << GFBITS];
-        let mut pi = [0i16; if (1 << GFBITS) > SYS_N {
-            1 << GFBITS
-        } else {
-            SYS_N
-        }];
-        let mut pivots = 0u64;
-
-        assert_eq!(sk_data.len(), sk.len());
-        assert_eq!(perm_data.len(), perm.len());
-
-        sk.copy_from_slice(sk_data.as_slice());
-        perm.copy_from_slice(perm_data.as_slice());
-
-        pk_gen(
-            sub!(mut pk, 0, CRYPTO_PUBLICKEYBYTES),
-            &mut sk,
-            &mut perm,
-            &mut pi,
-            &mut pivots,
-        )?;
-
-        let pk_expected = crate::TestData::new().u8vec("mceliece8192128f_pk_gen_pk_expected");
-        let sk_expected = crate::TestData::new().u8vec("mceliece8192128f_pk_gen_sk_expected");
-        let perm_expected = crate::TestData::new().u32vec("mceliece8192128f_pk_gen_perm_expected");
-        let pi_expected = crate::TestData::new().i16vec("mceliece8192128f_pk_gen_pi_expected");
-
-        assert_eq!(pk, pk_expected.as_slice());
-        assert_eq!(sk, sk_expected.as_slice());
-        assert_eq!(perm, perm_expected.as_slice());
-        assert_eq!(pi, pi_expected.as_slice());
-        assert_eq!(pivots, 0x1DFFFFFFF);
-
-        Ok(())
-    }
+            for row in 0..PK_NROWS {
+                mat_expected[row].copy_from_slice(&mat_expected_data[row * COLS..(row + 1) * COLS]);
+            }
 
-    #[test]
-    #[cfg(feature = "mceliece8192128f")]
-    fn test_pk_gen_2() -> Result<(), Box<dyn error::Error>> {
-        // NOTE expected pk_data of previous testcase becomes input for this one
-        let pk_data = crate::TestData::new().u8vec("mceliece8192128f_pk_gen_pk_expected");
-        let sk_data = crate::TestData::new().u8vec("mceliece8192128f_pk_gen_sk2_input");
-        let perm_data = crate::TestData::new().u32vec("mceliece8192128f_pk_gen_perm2_input");
-        let pi_data = crate::TestData::new().i16vec("mceliece8192128f_pk_gen_pi2_input");
-
-        let mut pk = vec![0u8; CRYPTO_PUBLICKEYBYTES];
-        let mut sk = [0u8; 2 * SYS_T];
-        let mut perm = [0u32; 1 << GFBITS];
-        let mut pi = [0i16; if (1 << GFBITS) > SYS_N {
-            1 << GFBITS
-        } else {
-            SYS_N
-        }];
-        let mut pivots = 0x1DFFFFFFF_u64;
-
-        assert_eq!(pk_data.len(), pk.len());
-        assert_eq!(sk_data.len(), sk.len());
-        assert_eq!(perm_data.len(), perm.len());
-        assert_eq!(pi_data.len(), pi.len());
-
-        pk.copy_from_slice(pk_data.as_slice());
-        sk.copy_from_slice(sk_data.as_slice());
-        perm.copy_from_slice(perm_data.as_slice());
-        pi.copy_from_slice(pi_data.as_slice());
-
-        pk_gen(
-            sub!(mut pk, 0, CRYPTO_PUBLICKEYBYTES),
-            &mut sk,
-            &mut perm,
-            &mut pi,
-            &mut pivots,
-        )?;
-
-        let pk_expected = crate::TestData::new().u8vec("mceliece8192128f_pk_gen_pk2_expected");
-        let sk_expected = crate::TestData::new().u8vec("mceliece8192128f_pk_gen_sk2_expected");
-        let perm_expected = crate::TestData::new().u32vec("mceliece8192128f_pk_gen_perm2_expected");
-        let pi_expected = crate::TestData::new().i16vec("mceliece8192128f_pk_gen_pi2_expected");
-
-        assert_eq!(pivots, 0xffffffff);
-        assert_eq!(sk, sk_expected.as_slice());
-        assert_eq!(pi, pi_expected.as_slice());
-        assert_eq!(perm, perm_expected.as_slice());
-        assert_eq!(pk, pk_expected.as_slice());
-
-        Ok(())
+            let pi_expected = crate::TestData::new().i16vec("mceliece8192128f_pi_expected");
+            let pivots_expected = 8053063679u64;
+
+            // comparison
+            assert_eq!(mat, mat_expected);
+            assert_eq!(pi, pi_expected);
+            assert_eq!(pivots, pivots_expected);
+        }
+
+        #[test]
+        fn test_pk_gen_1() {
+            let sk_data = crate::TestData::new().u8vec("mceliece8192128f_pk_gen_sk_input");
+            let perm_data = crate::TestData::new().u32vec("mceliece8192128f_pk_gen_perm_input");
+
+            let mut pk = vec![0u8; CRYPTO_PUBLICKEYBYTES];
+            let mut sk = [0u8; 2 * SYS_T];
+            let mut perm = [0u32; 1 << GFBITS];
+            let mut pi = [0i16; if (1 << GFBITS) > SYS_N {
+                1 << GFBITS
+            } else {
+                SYS_N
+            }];
+            let mut pivots = 0u64;
+
+            assert_eq!(sk_data.len(), sk.len());
+            assert_eq!(perm_data.len(), perm.len());
+
+            sk.copy_from_slice(sk_data.as_slice());
+            perm.copy_from_slice(perm_data.as_slice());
+
+            pk_gen(
+                sub!(mut pk, 0, CRYPTO_PUBLICKEYBYTES),
+                &sk,
+                &perm,
+                &mut pi,
+                &mut pivots,
+            );
+
+            let pk_expected = crate::TestData::new().u8vec("mceliece8192128f_pk_gen_pk_expected");
+            let sk_expected = crate::TestData::new().u8vec("mceliece8192128f_pk_gen_sk_expected");
+            let perm_expected =
+                crate::TestData::new().u32vec("mceliece8192128f_pk_gen_perm_expected");
+            let pi_expected = crate::TestData::new().i16vec("mceliece8192128f_pk_gen_pi_expected");
+
+            assert_eq!(pk, pk_expected.as_slice());
+            assert_eq!(sk, sk_expected.as_slice());
+            assert_eq!(perm, perm_expected.as_slice());
+            assert_eq!(pi, pi_expected.as_slice());
+            assert_eq!(pivots, 0x1DFFFFFFF);
+        }
+
+        #[test]
+        fn test_pk_gen_2() {
+            // NOTE expected pk_data of previous testcase becomes input for this one
+            let pk_data = crate::TestData::new().u8vec("mceliece8192128f_pk_gen_pk_expected");
+            let sk_data = crate::TestData::new().u8vec("mceliece8192128f_pk_gen_sk2_input");
+            let perm_data = crate::TestData::new().u32vec("mceliece8192128f_pk_gen_perm2_input");
+            let pi_data = crate::TestData::new().i16vec("mceliece8192128f_pk_gen_pi2_input");
+
+            let mut pk = vec![0u8; CRYPTO_PUBLICKEYBYTES];
+            let mut sk = [0u8; 2 * SYS_T];
+            let mut perm = [0u32; 1 << GFBITS];
+            let mut pi = [0i16; if (1 << GFBITS) > SYS_N {
+                1 << GFBITS
+            } else {
+                SYS_N
+            }];
+            let mut pivots = 0x1DFFFFFFF_u64;
+
+            assert_eq!(pk_data.len(), pk.len());
+            assert_eq!(sk_data.len(), sk.len());
+            assert_eq!(perm_data.len(), perm.len());
+            assert_eq!(pi_data.len(), pi.len());
+
+            pk.copy_from_slice(pk_data.as_slice());
+            sk.copy_from_slice(sk_data.as_slice());
+            perm.copy_from_slice(perm_data.as_slice());
+            pi.copy_from_slice(pi_data.as_slice());
+
+            pk_gen(
+                sub!(mut pk, 0, CRYPTO_PUBLICKEYBYTES),
+                &sk,
+                &perm,
+                &mut pi,
+                &mut pivots,
+            );
+
+            let pk_expected = crate::TestData::new().u8vec("mceliece8192128f_pk_gen_pk2_expected");
+            let sk_expected = crate::TestData::new().u8vec("mceliece8192128f_pk_gen_sk2_expected");
+            let perm_expected =
+                crate::TestData::new().u32vec("mceliece8192128f_pk_gen_perm2_expected");
+            let pi_expected = crate::TestData::new().i16vec("mceliece8192128f_pk_gen_pi2_expected");
+
+            assert_eq!(pivots, 0xffffffff);
+            assert_eq!(sk, sk_expected.as_slice());
+            assert_eq!(pi, pi_expected.as_slice());
+            assert_eq!(perm, perm_expected.as_slice());
+            assert_eq!(pk, pk_expected.as_slice());
+        }
     }
 }
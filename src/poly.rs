@@ -0,0 +1,337 @@
+//! Polynomials over GF(2^m) and elements of the extension field GF((2^m)^t)
+//!
+//! GF((2^m)^t) is represented as GF(2^m)[y]/F(y), where F is the field polynomial of the variant
+//! given by `FIELD_POLY_TERMS`. Its elements are polynomials of degree less than `SYS_T`.
+//! The Goppa polynomial of a secret key is the minimal polynomial of such an element.
+//! Coefficients are [`Gf`] values in the low `GFBITS` bits, the constant term comes first.
+
+use core::ops::{Add, Mul};
+
+use super::{
+    gf::{gf_add, gf_inv, gf_mul, gf_mul_inplace, Gf},
+    params::{FIELD_POLY_TERMS, GFBITS, SYS_T},
+    root::eval,
+    sk_gen::{genpoly_gen, irreducibility_check},
+};
+use crate::{error::Error, macros::sub};
+
+/// Checks that all `coefficients` are elements of GF(2^m)
+fn check_coefficients(coefficients: &[Gf]) -> Result<(), Error> {
+    match coefficients.iter().position(|&c| c >> GFBITS != 0) {
+        Some(index) => Err(Error::InvalidCoefficient { index }),
+        None => Ok(()),
+    }
+}
+
+/// An element of GF((2^m)^t), i.e. a polynomial of degree less than `SYS_T` modulo the field polynomial
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ExtFieldElem([Gf; SYS_T]);
+
+impl ExtFieldElem {
+    /// Returns the additive identity
+    pub fn zero() -> ExtFieldElem {
+        ExtFieldElem([0; SYS_T])
+    }
+
+    /// Returns the multiplicative identity
+    pub fn one() -> ExtFieldElem {
+        let mut c = [0; SYS_T];
+        c[0] = 1;
+        ExtFieldElem(c)
+    }
+
+    /// Creates the element with the given coefficients, missing higher coefficients are zero.
+    /// Returns `Error::InvalidLength` for more than `SYS_T` coefficients
+    /// and `Error::InvalidCoefficient` for coefficients exceeding `GFBITS` bits.
+    pub fn from_coefficients(coefficients: &[Gf]) -> Result<ExtFieldElem, Error> {
+        if coefficients.len() > SYS_T {
+            return Err(Error::InvalidLength {
+                expected: SYS_T,
+                actual: coefficients.len(),
+            });
+        }
+        check_coefficients(coefficients)?;
+
+        let mut c = [0; SYS_T];
+        c[..coefficients.len()].copy_from_slice(coefficients);
+        Ok(ExtFieldElem(c))
+    }
+
+    /// Returns the `SYS_T` coefficients
+    pub fn coefficients(&self) -> &[Gf; SYS_T] {
+        &self.0
+    }
+
+    /// Computes the minimal polynomial of the element over GF(2^m), which is monic of degree `SYS_T`.
+    /// Returns `Error::SubfieldElement` if the element lies in a proper subfield,
+    /// where `genpoly_gen` fails because the minimal polynomial has a lower degree.
+    pub fn minimal_polynomial(&self) -> Result<Poly, Error> {
+        let mut g = [0; SYS_T];
+
+        if genpoly_gen(&mut g, &self.0) != 0 {
+            return Err(Error::SubfieldElement);
+        }
+
+        let mut p = [0; SYS_T + 1];
+        p[..SYS_T].copy_from_slice(&g);
+        p[SYS_T] = 1;
+        Ok(Poly(p))
+    }
+}
+
+impl Add for &ExtFieldElem {
+    type Output = ExtFieldElem;
+
+    #[allow(clippy::needless_range_loop)]
+    fn add(self, rhs: &ExtFieldElem) -> ExtFieldElem {
+        let mut out = [0; SYS_T];
+        for i in 0..SYS_T {
+            out[i] = gf_add(self.0[i], rhs.0[i]);
+        }
+        ExtFieldElem(out)
+    }
+}
+
+impl Mul for &ExtFieldElem {
+    type Output = ExtFieldElem;
+
+    /// Multiplication modulo the field polynomial
+    fn mul(self, rhs: &ExtFieldElem) -> ExtFieldElem {
+        let mut out = [0; SYS_T];
+        gf_mul_inplace(&mut out, &self.0, &rhs.0);
+        ExtFieldElem(out)
+    }
+}
+
+/// A polynomial over GF(2^m) of degree at most `SYS_T`
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Poly([Gf; SYS_T + 1]);
+
+impl Poly {
+    /// Creates the polynomial with the given coefficients, missing higher coefficients are zero.
+    /// Returns `Error::InvalidLength` for more than `SYS_T + 1` coefficients
+    /// and `Error::InvalidCoefficient` for coefficients exceeding `GFBITS` bits.
+    pub fn from_coefficients(coefficients: &[Gf]) -> Result<Poly, Error> {
+        if coefficients.len() > SYS_T + 1 {
+            return Err(Error::InvalidLength {
+                expected: SYS_T + 1,
+                actual: coefficients.len(),
+            });
+        }
+        check_coefficients(coefficients)?;
+
+        let mut c = [0; SYS_T + 1];
+        c[..coefficients.len()].copy_from_slice(coefficients);
+        Ok(Poly(c))
+    }
+
+    /// Returns the field polynomial F defining GF((2^m)^t), which is monic of degree `SYS_T`
+    pub fn field_polynomial() -> Poly {
+        let mut c = [0; SYS_T + 1];
+        for &(degree, coefficient) in FIELD_POLY_TERMS {
+            c[degree] = coefficient;
+        }
+        c[SYS_T] = 1;
+        Poly(c)
+    }
+
+    /// Returns the `SYS_T + 1` coefficients
+    pub fn coefficients(&self) -> &[Gf; SYS_T + 1] {
+        &self.0
+    }
+
+    /// Returns the degree or `None` for the zero polynomial
+    pub fn degree(&self) -> Option<usize> {
+        self.0.iter().rposition(|&c| c != 0)
+    }
+
+    /// Evaluates the polynomial at `a`. Only the low `GFBITS` bits of `a` are used.
+    pub fn eval(&self, a: Gf) -> Gf {
+        eval(&self.0, a & ((1 << GFBITS) - 1))
+    }
+
+    /// Evaluates the polynomial at every element of `points` and stores the results in `out`.
+    /// Returns `Error::InvalidLength` unless both have the same length.
+    pub fn eval_many(&self, points: &[Gf], out: &mut [Gf]) -> Result<(), Error> {
+        if points.len() != out.len() {
+            return Err(Error::InvalidLength {
+                expected: points.len(),
+                actual: out.len(),
+            });
+        }
+
+        for (y, &a) in out.iter_mut().zip(points) {
+            *y = self.eval(a);
+        }
+
+        Ok(())
+    }
+
+    /// Evaluates the polynomial at the element `x` of GF((2^m)^t)
+    pub fn eval_ext(&self, x: &ExtFieldElem) -> ExtFieldElem {
+        let mut r = ExtFieldElem::zero();
+
+        for &c in self.0.iter().rev() {
+            r = &r * x;
+            r.0[0] = gf_add(r.0[0], c);
+        }
+
+        r
+    }
+
+    /// Multiplies every coefficient by the element `a` of GF(2^m)
+    #[allow(clippy::needless_range_loop)]
+    fn scale(&self, a: Gf) -> Poly {
+        let mut c = [0; SYS_T + 1];
+        for i in 0..=SYS_T {
+            c[i] = gf_mul(self.0[i], a);
+        }
+        Poly(c)
+    }
+
+    /// Determines whether the polynomial is irreducible over GF(2^m).
+    /// Returns `Error::InvalidDegree` unless it has degree `SYS_T`, as required by Rabin's test.
+    pub fn is_irreducible(&self) -> Result<bool, Error> {
+        let leading = self.0[SYS_T];
+        if leading == 0 {
+            return Err(Error::InvalidDegree {
+                expected: SYS_T,
+                actual: self.degree().unwrap_or(0),
+            });
+        }
+
+        // irreducibility is invariant under scaling, `irreducibility_check` expects a monic polynomial
+        let monic = self.scale(gf_inv(leading));
+
+        Ok(irreducibility_check(sub!(monic.0, 0, SYS_T, u16)) == 0)
+    }
+}
+
+#[cfg(test)]
+variant_tests! {
+    [mceliece348864, mceliece460896, mceliece6960119]
+
+    mod tests {
+        use super::*;
+
+        #[allow(clippy::needless_range_loop)]
+        fn sample_element(seed: usize) -> ExtFieldElem {
+            let mut c = [0; SYS_T];
+            for i in 0..SYS_T {
+                c[i] = ((i * 1103 + seed * 4099 + 17) % (1 << GFBITS)) as Gf;
+            }
+            ExtFieldElem(c)
+        }
+
+        #[test]
+        fn test_ext_field_arithmetic() {
+            let (a, b, c) = (sample_element(1), sample_element(2), sample_element(3));
+
+            assert_eq!(&a * &b, &b * &a);
+            assert_eq!(&(&a * &b) * &c, &a * &(&b * &c));
+            assert_eq!(&a * &(&b + &c), &(&a * &b) + &(&a * &c));
+            assert_eq!(&a * &ExtFieldElem::one(), a);
+            assert_eq!(&a + &a, ExtFieldElem::zero());
+
+            // y^t reduces to the lower terms of the field polynomial
+            let y = ExtFieldElem::from_coefficients(&[0, 1]).unwrap();
+            let mut y_power = ExtFieldElem::one();
+            for _ in 0..SYS_T {
+                y_power = &y_power * &y;
+            }
+            assert_eq!(
+                &y_power.coefficients()[..],
+                &Poly::field_polynomial().coefficients()[..SYS_T]
+            );
+
+            // the field polynomial vanishes at y
+            assert_eq!(Poly::field_polynomial().eval_ext(&y), ExtFieldElem::zero());
+            assert_eq!(Poly::field_polynomial().is_irreducible(), Ok(true));
+        }
+
+        variant_tests! {
+            [mceliece348864, mceliece6960119]
+
+            #[test]
+            #[allow(clippy::manual_is_multiple_of)]
+            fn test_minimal_polynomial() -> Result<(), Error> {
+                let f = sample_element(5);
+                let g = f.minimal_polynomial()?;
+                assert_eq!(g.degree(), Some(SYS_T));
+                assert_eq!(g.eval_ext(&f), ExtFieldElem::zero());
+                assert_eq!(g.is_irreducible(), Ok(true));
+                assert_eq!(g.scale(7).is_irreducible(), Ok(true));
+
+                // elements of GF(2^m) have minimal polynomials of degree 1
+                let constant = ExtFieldElem::from_coefficients(&[1234])?;
+                assert_eq!(constant.minimal_polynomial(), Err(Error::SubfieldElement));
+                assert_eq!(
+                    ExtFieldElem::zero().minimal_polynomial(),
+                    Err(Error::SubfieldElement)
+                );
+
+                // y^t + 5 is the square of y^(t/2) + sqrt(5) for even t
+                if SYS_T % 2 == 0 {
+                    let mut c = [0; SYS_T + 1];
+                    c[SYS_T] = 1;
+                    c[0] = 5;
+                    assert_eq!(Poly(c).is_irreducible(), Ok(false));
+                }
+
+                let x_plus_one = Poly::from_coefficients(&[1, 1])?;
+                assert_eq!(
+                    x_plus_one.is_irreducible(),
+                    Err(Error::InvalidDegree {
+                        expected: SYS_T,
+                        actual: 1
+                    })
+                );
+
+                Ok(())
+            }
+        }
+
+        variant_tests! {
+            [mceliece348864]
+
+            #[test]
+            fn test_poly_eval() -> Result<(), Error> {
+                // (x + 3)(x + 5) = x^2 + 6x + 15 in characteristic 2
+                let p = Poly::from_coefficients(&[gf_mul(3, 5), 6, 1])?;
+                assert_eq!(p.degree(), Some(2));
+                assert_eq!(p.eval(3), 0);
+                assert_eq!(p.eval(5), 0);
+                assert_eq!(p.eval(0), gf_mul(3, 5));
+
+                let points = [0, 1, 3, 5, 4095];
+                let mut out = [0; 5];
+                p.eval_many(&points, &mut out)?;
+                for (&a, &y) in points.iter().zip(&out) {
+                    assert_eq!(y, p.eval(a));
+                }
+                assert_eq!(
+                    p.eval_many(&points, &mut out[1..]),
+                    Err(Error::InvalidLength {
+                        expected: 5,
+                        actual: 4
+                    })
+                );
+
+                assert_eq!(
+                    Poly::from_coefficients(&[1, 1 << GFBITS]),
+                    Err(Error::InvalidCoefficient { index: 1 })
+                );
+                assert_eq!(
+                    Poly::from_coefficients(&[0; SYS_T + 2]),
+                    Err(Error::InvalidLength {
+                        expected: SYS_T + 1,
+                        actual: SYS_T + 2
+                    })
+                );
+                assert_eq!(Poly::from_coefficients(&[])?.degree(), None);
+
+                Ok(())
+            }
+        }
+    }
+}
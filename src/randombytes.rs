@@ -3,39 +3,53 @@
 //!
 //! The implementation follows the design discussed in this blogpost:
 //! <https://lukas-prokop.at/articles/2021-12-31-nists-rng-in-rust>
+//!
+//! The KEM functions accept any `rand_core::CryptoRng + RngCore`.
+//! `AesState` implements these traits and `RNGStateAdapter` wraps other `RNGState` implementations.
+//! With the `getrandom` feature, `OsRng` provides randomness of the operating system.
 
 use aes::BlockEncrypt;
 use aes::NewBlockCipher;
-use std::error;
-use std::fmt;
+use core::fmt;
+use rand_core::{impls, CryptoRng, RngCore, SeedableRng};
+
+use crate::error::Error;
 
 /// Trait requiring primitives to generate pseudo-random numbers.
 /// `AesState` is an object implementing this trait.
 pub trait RNGState {
     /// Fill the buffer `x` with pseudo-random bytes resulting from the
-    /// RNG run updating the RNG state
-    fn randombytes(&mut self, x: &mut [u8]) -> Result<(), Box<dyn error::Error>>;
+    /// RNG run updating the RNG state. Failures are reported as `Error::RngFailure`.
+    fn randombytes(&mut self, x: &mut [u8]) -> Result<(), Error>;
     /// Initialize/reset the RNG state based on the seed provided as `entropy_input`
     fn randombytes_init(&mut self, entropy_input: [u8; 48]);
 }
 
-/// AesState is a struct storing data of a pseudo-random number generator.
+/// AesState is the deterministic random bit generator (DRBG) used by NIST
+/// to generate the known-answer tests (KATs) of the submissions.
 /// Using `randombytes_init`, it can be initialized once. Using `randombytes`,
 /// one can successively fetch new pseudo-random numbers.
+///
+/// Its output is determined by the seed, hence it is meant for reproducing test vectors.
+/// Use `OsRng` to generate keys in production. Using a state which has not been seeded
+/// fails with `Error::UnseededRng` (or panics in `RngCore::fill_bytes`).
 #[derive(Clone, Debug, PartialEq)]
 pub struct AesState {
     pub key: [u8; 32],
     pub v: [u8; 16],
-    pub reseed_counter: i32,
+    /// Number of requests since seeding (plus one), saturating at `u64::MAX`
+    pub reseed_counter: u64,
+    seeded: bool,
 }
 
 impl AesState {
-    /// Returns a fresh RNG state
+    /// Returns a fresh RNG state, which must be seeded before use
     pub fn new() -> AesState {
         AesState {
             key: [0; 32],
             v: [0; 16],
             reseed_counter: 0,
+            seeded: false,
         }
     }
 
@@ -48,6 +62,34 @@ impl AesState {
         cipher.encrypt_block(buffer.into());
     }
 
+    /// Returns whether the state has been seeded by `randombytes_init`
+    pub fn is_seeded(&self) -> bool {
+        self.seeded
+    }
+
+    /// Fill the buffer `x` with pseudo-random bytes and update the state.
+    /// Fails if the state has not been seeded.
+    fn generate(&mut self, x: &mut [u8]) -> Result<(), Error> {
+        if !self.is_seeded() {
+            return Err(Error::UnseededRng);
+        }
+
+        for chunk in x.chunks_mut(16) {
+            let count = u128::from_be_bytes(self.v);
+            self.v.copy_from_slice(&(count + 1).to_be_bytes());
+
+            let mut block = [0u8; 16];
+            Self::aes256_ecb(&self.key, &self.v, &mut block);
+
+            (*chunk).copy_from_slice(&block[..chunk.len()]);
+        }
+
+        Self::aes256_ctr_update(&mut None, &mut self.key, &mut self.v);
+        self.reseed_counter = self.reseed_counter.saturating_add(1);
+
+        Ok(())
+    }
+
     /// Update `key` and `v` with `provided_data` by running one round of AES in counter mode
     fn aes256_ctr_update(
         provided_data: &mut Option<[u8; 48]>,
@@ -80,34 +122,110 @@ impl AesState {
 impl RNGState for AesState {
     /// Fill the buffer `x` with pseudo-random bytes resulting from the
     /// AES run in counter mode updating the object state
-    fn randombytes(&mut self, x: &mut [u8]) -> Result<(), Box<dyn error::Error>> {
-        for chunk in x.chunks_mut(16) {
-            let count = u128::from_be_bytes(self.v);
-            self.v.copy_from_slice(&(count + 1).to_be_bytes());
-
-            let mut block = [0u8; 16];
-            Self::aes256_ecb(&self.key, &self.v, &mut block);
-
-            (*chunk).copy_from_slice(&block[..chunk.len()]);
-        }
-
-        Self::aes256_ctr_update(&mut None, &mut self.key, &mut self.v);
-        self.reseed_counter += 1;
-
-        Ok(())
+    fn randombytes(&mut self, x: &mut [u8]) -> Result<(), Error> {
+        self.generate(x)
     }
 
     /// Initialize/reset the state based on the seed provided as `entropy_input`
     fn randombytes_init(&mut self, entropy_input: [u8; 48]) {
         self.key = [0u8; 32];
         self.v = [0u8; 16];
-        self.reseed_counter = 1i32;
 
         Self::aes256_ctr_update(&mut Some(entropy_input), &mut self.key, &mut self.v);
         self.reseed_counter = 1;
+        self.seeded = true;
     }
 }
 
+/// Forwarding implementation, which allows to pass `&mut dyn RNGState`
+/// where an `impl RNGState` is expected.
+impl<R: RNGState + ?Sized> RNGState for &mut R {
+    fn randombytes(&mut self, x: &mut [u8]) -> Result<(), Error> {
+        (**self).randombytes(x)
+    }
+
+    fn randombytes_init(&mut self, entropy_input: [u8; 48]) {
+        (**self).randombytes_init(entropy_input)
+    }
+}
+
+/// Every call of `fill_bytes` corresponds to one call of `randombytes`.
+/// Hence, the output is identical to the one of the NIST DRBG for the same sequence of requests.
+impl RngCore for AesState {
+    fn next_u32(&mut self) -> u32 {
+        impls::next_u32_via_fill(self)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        impls::next_u64_via_fill(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.generate(dest)
+            .expect("AesState must be seeded before generating random bytes")
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        Ok(self.generate(dest)?)
+    }
+}
+
+impl CryptoRng for AesState {}
+
+/// The 48 bytes of `entropy_input` used to seed an `AesState`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AesSeed(pub [u8; 48]);
+
+impl Default for AesSeed {
+    fn default() -> Self {
+        AesSeed([0u8; 48])
+    }
+}
+
+impl AsMut<[u8]> for AesSeed {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+impl SeedableRng for AesState {
+    type Seed = AesSeed;
+
+    /// Returns a state initialized by `randombytes_init`
+    fn from_seed(seed: AesSeed) -> Self {
+        let mut state = AesState::new();
+        state.randombytes_init(seed.0);
+        state
+    }
+}
+
+/// Adapter implementing `RngCore` and `CryptoRng` for any `RNGState`,
+/// such that existing implementations can be passed to the KEM functions.
+/// The wrapped generator must be cryptographically secure.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RNGStateAdapter<R: RNGState>(pub R);
+
+impl<R: RNGState> RngCore for RNGStateAdapter<R> {
+    fn next_u32(&mut self) -> u32 {
+        impls::next_u32_via_fill(self)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        impls::next_u64_via_fill(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.try_fill_bytes(dest)
+            .expect("RNGState failed to generate random bytes")
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        Ok(self.0.randombytes(dest)?)
+    }
+}
+
+impl<R: RNGState> CryptoRng for RNGStateAdapter<R> {}
+
 impl Default for AesState {
     fn default() -> Self {
         Self::new()
@@ -122,6 +240,7 @@ impl fmt::Display for AesState {
         writeln!(f, "  key = {:?}", self.key)?;
         writeln!(f, "  v   = {:?}", self.v)?;
         writeln!(f, "  reseed_counter = {}", self.reseed_counter)?;
+        writeln!(f, "  seeded = {}", self.seeded)?;
         writeln!(f, "}}")
     }
 }
@@ -132,7 +251,7 @@ mod tests {
     use std::convert::TryFrom;
 
     #[test]
-    fn test_rng() -> Result<(), Box<dyn error::Error>> {
+    fn test_rng() -> Result<(), Error> {
         let mut data = [0u8; 256];
         let mut entropy_input = [0u8; 48];
         let mut personalization_string = [0u8; 48];
@@ -140,7 +259,7 @@ mod tests {
 
         for i in 0..48 {
             entropy_input[i] = i as u8;
-            personalization_string[i] = 0 as u8;
+            personalization_string[i] = 0u8;
         }
 
         rng_state.randombytes_init(entropy_input);
@@ -157,4 +276,119 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_rng_core() -> Result<(), Error> {
+        let mut expected = [0u8; 100];
+        let mut rng_state = AesState::new();
+        rng_state.randombytes_init([7u8; 48]);
+        rng_state.randombytes(&mut expected)?;
+
+        let mut data = [0u8; 100];
+        let mut rng = AesState::from_seed(AesSeed([7u8; 48]));
+        rng.fill_bytes(&mut data);
+        assert_eq!(data, expected);
+
+        let mut data = [0u8; 100];
+        let mut adapter = RNGStateAdapter(AesState::new());
+        adapter.0.randombytes_init([7u8; 48]);
+        adapter.try_fill_bytes(&mut data).unwrap();
+        assert_eq!(data, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_unseeded() {
+        use crate::mceliece348864::{
+            crypto_kem_keypair, CRYPTO_PUBLICKEYBYTES, CRYPTO_SECRETKEYBYTES,
+        };
+
+        let mut rng = AesState::new();
+        assert!(!rng.is_seeded());
+        assert_eq!(rng.randombytes(&mut [0u8; 16]), Err(Error::UnseededRng));
+        assert_eq!(
+            rng.try_fill_bytes(&mut [0u8; 16]).map_err(Error::from),
+            Err(Error::UnseededRng)
+        );
+        assert_eq!(
+            RNGStateAdapter(AesState::new())
+                .try_fill_bytes(&mut [0u8; 16])
+                .map_err(Error::from),
+            Err(Error::UnseededRng)
+        );
+
+        let mut pk = vec![0u8; CRYPTO_PUBLICKEYBYTES];
+        let result = crypto_kem_keypair(
+            pk.as_mut_slice().try_into().unwrap(),
+            &mut [0u8; CRYPTO_SECRETKEYBYTES],
+            &mut rng,
+        );
+        assert_eq!(result, Err(Error::UnseededRng));
+        assert!(std::panic::catch_unwind(|| AesState::new().next_u32()).is_err());
+
+        rng.randombytes_init([0u8; 48]);
+        assert!(rng.is_seeded());
+    }
+
+    #[test]
+    fn test_reseed_counter_saturates() -> Result<(), Error> {
+        let mut rng = AesState::new();
+        rng.randombytes_init([8u8; 48]);
+        let mut expected = [0u8; 16];
+        rng.clone().randombytes(&mut expected)?;
+
+        // the counter does not affect the output or the seeded state
+        rng.reseed_counter = u64::MAX;
+        let mut data = [0u8; 16];
+        rng.randombytes(&mut data)?;
+        assert_eq!(data, expected);
+        assert_eq!(rng.reseed_counter, u64::MAX);
+        assert!(rng.is_seeded());
+
+        rng.reseed_counter = 0;
+        rng.randombytes(&mut data)?;
+        assert_eq!(rng.reseed_counter, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "getrandom")]
+    fn test_os_rng() {
+        let mut a = [0u8; 32];
+        let mut b = [0u8; 32];
+        crate::OsRng.fill_bytes(&mut a);
+        crate::OsRng.fill_bytes(&mut b);
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_adapter_failure() {
+        use crate::mceliece348864::{
+            crypto_kem_enc, CRYPTO_BYTES, CRYPTO_CIPHERTEXTBYTES, CRYPTO_PUBLICKEYBYTES,
+        };
+
+        struct FailingRng;
+
+        impl RNGState for FailingRng {
+            fn randombytes(&mut self, _x: &mut [u8]) -> Result<(), Error> {
+                Err(Error::RngFailure)
+            }
+
+            fn randombytes_init(&mut self, _entropy_input: [u8; 48]) {}
+        }
+
+        let mut rng = RNGStateAdapter(FailingRng);
+        assert!(rng.try_fill_bytes(&mut [0u8; 4]).is_err());
+
+        let pk = vec![0u8; CRYPTO_PUBLICKEYBYTES];
+        let result = crypto_kem_enc(
+            &mut [0u8; CRYPTO_CIPHERTEXTBYTES],
+            &mut [0u8; CRYPTO_BYTES],
+            pk.as_slice().try_into().unwrap(),
+            &mut rng,
+        );
+        assert_eq!(result, Err(Error::RngFailure));
+    }
 }
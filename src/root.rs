@@ -1,6 +1,6 @@
 //! This file is for evaluating a polynomial at one or more field elements
 
-use crate::{
+use super::{
     gf::{gf_add, gf_mul, Gf},
     params::{SYS_N, SYS_T},
 };
@@ -27,27 +27,32 @@ pub(crate) fn root(out: &mut [Gf; SYS_N], f: &[Gf; SYS_T + 1], l: &[Gf; SYS_N])
 
 #[cfg(test)]
 mod tests {
+    use super::super::CRYPTO_PRIMITIVE;
     use super::*;
-    use crate::api::CRYPTO_PRIMITIVE;
 
-    #[test]
-    #[cfg(feature = "mceliece8192128f")]
-    fn test_root_simple() {
-        let g = [1u16; SYS_T + 1];
-        let mut l = [0u16; SYS_N];
-        let mut inv = [0u16; SYS_N];
+    variant_tests! {
+        [mceliece8192128f]
 
-        for i in 0..l.len() {
-            l[i] = i as u16;
-        }
+        #[test]
+        #[allow(clippy::needless_range_loop)]
+        fn test_root_simple() {
+            let g = [1u16; SYS_T + 1];
+            let mut l = [0u16; SYS_N];
+            let mut inv = [0u16; SYS_N];
 
-        root(&mut inv, &g, &l);
+            for i in 0..l.len() {
+                l[i] = i as u16;
+            }
 
-        let expected = crate::TestData::new().u16vec("mceliece8192128f_root_inv_expected");
-        assert_eq!(expected, inv);
+            root(&mut inv, &g, &l);
+
+            let expected = crate::TestData::new().u16vec("mceliece8192128f_root_inv_expected");
+            assert_eq!(expected, inv);
+        }
     }
 
     #[test]
+    #[allow(clippy::needless_range_loop)]
     fn test_root() {
         let mut out = [0u16; SYS_N];
         let mut f = [0u16; SYS_T + 1];
@@ -63,9 +68,9 @@ mod tests {
 
         root(&mut out, &f, &l);
 
-        let mut name = format!("{}_root_out_expected", CRYPTO_PRIMITIVE);
-        // NOTE the f-variants equals the non-f variants. We only stored the non-f variants
-        name = name.replace("f_root_out", "_root_out");
+        // the test data is only stored for the base variants
+        let variant = crate::TestData::base_variant(CRYPTO_PRIMITIVE);
+        let name = format!("{}_root_out_expected", variant);
         let expected = crate::TestData::new().u16vec(&name);
         assert_eq!(expected, out);
     }
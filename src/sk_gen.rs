@@ -1,23 +1,24 @@
 //! Generation of secret key
 
-use crate::gf::{gf_inv, gf_iszero, gf_mul, gf_mul_inplace, Gf};
-use crate::params::SYS_T;
+use super::gf::{gf_inv, gf_iszero, gf_mul, gf_mul_inplace, Gf};
+use super::params::{GFBITS, SYS_T};
+use super::util::{large_array, LargeArray};
+use crate::macros::zeroize;
 
 /// Take element `f` in `GF((2^m)^t)` and return minimal polynomial `out` of `f`
 /// Returns 0 for success and -1 for failure
+#[allow(clippy::needless_range_loop)]
 pub(crate) fn genpoly_gen(out: &mut [Gf; SYS_T], f: &[Gf; SYS_T]) -> isize {
     let mut mat = [[0u16; SYS_T]; SYS_T + 1];
     mat[0][0] = 1;
 
     mat[0][1..SYS_T].fill(0);
 
-    for i in 0..SYS_T {
-        mat[1][i] = f[i];
-    }
+    mat[1] = *f;
 
     for j in 2..=SYS_T {
         let (left, right) = mat.split_at_mut(j);
-        gf_mul_inplace(&mut right[0], &mut left[j - 1], f);
+        gf_mul_inplace(&mut right[0], &left[j - 1], f);
     }
 
     for j in 0..SYS_T {
@@ -32,6 +33,7 @@ pub(crate) fn genpoly_gen(out: &mut [Gf; SYS_T], f: &[Gf; SYS_T]) -> isize {
         }
 
         if mat[j][j] == 0 {
+            zeroize!(mat);
             return -1;
         }
 
@@ -54,32 +56,235 @@ pub(crate) fn genpoly_gen(out: &mut [Gf; SYS_T], f: &[Gf; SYS_T]) -> isize {
 
     out[0..SYS_T].copy_from_slice(&mat[SYS_T][0..SYS_T]);
 
+    zeroize!(mat);
+
     0
 }
 
+/// Multiply polynomials `in0` and `in1` of degree less than `SYS_T` modulo the monic polynomial
+/// of degree `SYS_T` with lower coefficients `g` and store the result in `out`
+fn poly_mulmod(out: &mut [Gf; SYS_T], in0: &[Gf; SYS_T], in1: &[Gf; SYS_T], g: &[Gf; SYS_T]) {
+    let mut prod: [Gf; SYS_T * 2 - 1] = [0; SYS_T * 2 - 1];
+
+    for i in 0..SYS_T {
+        for j in 0..SYS_T {
+            prod[i + j] ^= gf_mul(in0[i], in1[j]);
+        }
+    }
+
+    for i in (SYS_T..=(SYS_T - 1) * 2).rev() {
+        for j in 0..SYS_T {
+            prod[i - SYS_T + j] ^= gf_mul(prod[i], g[j]);
+        }
+    }
+
+    out[0..SYS_T].copy_from_slice(&prod[0..SYS_T]);
+
+    zeroize!(prod);
+}
+
+/// Apply the Frobenius map `a -> a^(2^m)` given by the matrix `frob` to `a` in place
+fn frobenius(a: &mut [Gf; SYS_T], frob: &[[Gf; SYS_T]; SYS_T]) {
+    let mut out = [0u16; SYS_T];
+
+    for i in 0..SYS_T {
+        for j in 0..SYS_T {
+            out[j] ^= gf_mul(a[i], frob[i][j]);
+        }
+    }
+
+    a.copy_from_slice(&out);
+
+    zeroize!(out);
+}
+
+/// Determine whether the polynomial `h` of degree less than `SYS_T` is invertible modulo
+/// the monic polynomial with lower coefficients `g`, i.e. whether both are coprime.
+/// Returns 0 if so and -1 otherwise. The elimination runs in constant time.
+#[allow(clippy::needless_range_loop)]
+fn coprime_check(h: &[Gf; SYS_T], g: &[Gf; SYS_T]) -> isize {
+    // the rows are h * x^i mod g, which are linearly independent iff h is invertible
+    let mut mat: LargeArray<[Gf; SYS_T], SYS_T> = large_array([0u16; SYS_T]);
+    mat[0] = *h;
+
+    for i in 1..SYS_T {
+        let top = mat[i - 1][SYS_T - 1];
+        for j in (1..SYS_T).rev() {
+            mat[i][j] = mat[i - 1][j - 1] ^ gf_mul(top, g[j]);
+        }
+        mat[i][0] = gf_mul(top, g[0]);
+    }
+
+    let mut singular = 0;
+
+    for j in 0..SYS_T {
+        for k in (j + 1)..SYS_T {
+            let mask = gf_iszero(mat[j][j]);
+
+            for c in j..SYS_T {
+                mat[j][c] ^= mat[k][c] & mask;
+            }
+        }
+
+        // a zero pivot remains zero, gf_inv(0) = 0 and the elimination continues without effect
+        singular |= gf_iszero(mat[j][j]);
+
+        let inv = gf_inv(mat[j][j]);
+
+        for k in (j + 1)..SYS_T {
+            let t = gf_mul(mat[k][j], inv);
+
+            for c in j..SYS_T {
+                mat[k][c] ^= gf_mul(mat[j][c], t);
+            }
+        }
+    }
+
+    zeroize!(mat);
+
+    -((singular & 1) as isize)
+}
+
+/// Determine whether `n` is a prime number
+#[allow(clippy::manual_is_multiple_of)]
+fn is_prime(n: usize) -> bool {
+    n >= 2 && (2..n).take_while(|d| d * d <= n).all(|d| n % d != 0)
+}
+
+/// Determine whether the monic polynomial of degree `SYS_T` with lower coefficients `g`
+/// is irreducible over `GF(2^m)` using Rabin's test.
+/// Returns 0 for an irreducible polynomial and -1 otherwise.
+/// The check runs in constant time: the branches only depend on `SYS_T`.
+#[allow(clippy::manual_is_multiple_of)]
+pub(crate) fn irreducibility_check(g: &[Gf; SYS_T]) -> isize {
+    // frob[i] = x^(q*i) mod g with q = 2^m
+    let mut frob: LargeArray<[Gf; SYS_T], SYS_T> = large_array([0u16; SYS_T]);
+    let mut xq = [0u16; SYS_T];
+    let mut tmp = [0u16; SYS_T];
+
+    xq[1] = 1;
+    for _ in 0..GFBITS {
+        poly_mulmod(&mut tmp, &xq, &xq, g);
+        xq.copy_from_slice(&tmp);
+    }
+
+    frob[0][0] = 1;
+    frob[1] = xq;
+    for i in 2..SYS_T {
+        let (left, right) = frob.split_at_mut(i);
+        poly_mulmod(&mut right[0], &left[i - 1], &xq, g);
+    }
+
+    // a monic g of degree t is irreducible iff x^(q^t) = x mod g and
+    // gcd(x^(q^(t/p)) - x, g) = 1 for all prime divisors p of t
+    let mut ret = 0;
+    let mut power = [0u16; SYS_T];
+    power[1] = 1;
+
+    for k in 1..=SYS_T {
+        frobenius(&mut power, &frob);
+
+        if SYS_T % k == 0 && is_prime(SYS_T / k) {
+            tmp.copy_from_slice(&power);
+            tmp[1] ^= 1;
+
+            ret |= coprime_check(&tmp, g);
+        }
+    }
+
+    power[1] ^= 1;
+    let mut diff = 0;
+    for c in power {
+        diff |= c;
+    }
+    ret |= (gf_iszero(diff) & 1) as isize - 1;
+
+    zeroize!(frob, xq, tmp, power, diff);
+
+    ret
+}
+
 #[cfg(test)]
-#[cfg(all(feature = "mceliece8192128f", test))]
-mod tests {
-    use super::*;
-    use crate::macros::sub;
-    use std::error;
+variant_tests! {
+    [mceliece348864, mceliece6960119, mceliece8192128f]
+
+    mod tests {
+        use super::*;
+        use crate::macros::sub;
 
-    #[test]
-    fn test_genpoly_gen() -> Result<(), Box<dyn error::Error>> {
-        assert_eq!(SYS_T, 128);
+        variant_tests! {
+            [mceliece8192128f]
 
-        let input_src =
-            crate::TestData::new().u16vec("mceliece8192128f_sk_gen_genpoly_1st_round_input");
-        let first_round_input = sub!(input_src, 0, 128, u16);
-        let first_round_output =
-            crate::TestData::new().u16vec("mceliece8192128f_sk_gen_genpoly_1st_round_output");
+            #[test]
+            fn test_genpoly_gen() {
+                assert_eq!(SYS_T, 128);
 
-        let mut output = [0u16; SYS_T];
+                let input_src = crate::TestData::new()
+                    .u16vec("mceliece8192128f_sk_gen_genpoly_1st_round_input");
+                let first_round_input = sub!(input_src, 0, SYS_T, u16);
+                let first_round_output = crate::TestData::new()
+                    .u16vec("mceliece8192128f_sk_gen_genpoly_1st_round_output");
 
-        genpoly_gen(&mut output, first_round_input);
+                let mut output = [0u16; SYS_T];
 
-        assert_eq!(&output, first_round_output.as_slice());
+                genpoly_gen(&mut output, first_round_input);
 
-        Ok(())
+                assert_eq!(&output, first_round_output.as_slice());
+            }
+        }
+
+        variant_tests! {
+            [mceliece348864, mceliece6960119]
+
+            #[test]
+            #[allow(clippy::needless_range_loop)]
+            fn test_irreducibility_check() {
+                // minimal polynomials of elements of GF((2^m)^t) are irreducible
+                let mut f = [0u16; SYS_T];
+                let mut g = [0u16; SYS_T];
+                for i in 0..SYS_T {
+                    f[i] = ((i * 1103 + 17) % (1 << GFBITS)) as u16;
+                }
+                assert_eq!(genpoly_gen(&mut g, &f), 0);
+                assert_eq!(irreducibility_check(&g), 0);
+
+                // x^t and x^t + 1 = (x + 1)^t
+                let mut g = [0u16; SYS_T];
+                assert_eq!(irreducibility_check(&g), -1);
+                g[0] = 1;
+                assert_eq!(irreducibility_check(&g), -1);
+
+                // product of t distinct linear factors, which divides x^(q^t) - x
+                let mut g = [0u16; SYS_T + 1];
+                g[0] = 1;
+                for a in 1..=SYS_T as u16 {
+                    for i in (0..=SYS_T).rev() {
+                        g[i] = gf_mul(g[i], a) ^ if i > 0 { g[i - 1] } else { 0 };
+                    }
+                }
+                assert_eq!(g[SYS_T], 1);
+                assert_eq!(irreducibility_check(sub!(g, 0, SYS_T, u16)), -1);
+            }
+
+            #[test]
+            fn test_coprime_check() {
+                // g = x^t + x + 1
+                let mut g = [0u16; SYS_T];
+                g[0] = 1;
+                g[1] = 1;
+
+                let mut h = [0u16; SYS_T];
+                assert_eq!(coprime_check(&h, &g), -1);
+                h[0] = 1;
+                assert_eq!(coprime_check(&h, &g), 0);
+                h[0] = 0;
+                h[1] = 1;
+                assert_eq!(coprime_check(&h, &g), 0);
+
+                // x divides both x and x^t + x
+                g[0] = 0;
+                assert_eq!(coprime_check(&h, &g), -1);
+            }
+        }
     }
 }
@@ -1,11 +1,12 @@
 //! Syndrome computation
 
-use crate::gf::{gf_add, gf_inv, gf_mul, Gf};
-use crate::params::{SYS_N, SYS_T};
-use crate::root::eval;
+use super::gf::{gf_add, gf_inv, gf_mul, Gf};
+use super::params::{SYS_N, SYS_T};
+use super::root::eval;
 
 /// Given Goppa polynomial `f`, support `l`, and received word `r`
 /// compute `out`, the syndrome of length 2t
+#[allow(clippy::needless_range_loop)]
 pub(crate) fn synd(
     out: &mut [Gf; SYS_T * 2],
     f: &[Gf; SYS_T + 1],
@@ -1,7 +1,7 @@
 //! Matrix transpose implementation
 
 /// Compute transposition of `input` and store it in `output`
-#[cfg(not(any(feature = "mceliece348864", feature = "mceliece348864f")))]
+#[allow(clippy::identity_op)]
 pub(crate) fn transpose(output: &mut [u64; 64], input: [u64; 64]) {
     let masks: [[u64; 2]; 6] = [
         [0x5555555555555555, 0xAAAAAAAAAAAAAAAA],
@@ -12,9 +12,7 @@ pub(crate) fn transpose(output: &mut [u64; 64], input: [u64; 64]) {
         [0x00000000FFFFFFFF, 0xFFFFFFFF00000000],
     ];
 
-    for h in 0..64 {
-        output[h] = input[h];
-    }
+    *output = input;
 
     for d in (0..=5).rev() {
         let s = 1 << d;
@@ -39,7 +37,6 @@ pub(crate) fn transpose(output: &mut [u64; 64], input: [u64; 64]) {
 /// input argument == output argument. Because we cannot create a
 /// shared and mutable reference simultaneously, we can only generate
 /// one argument.
-#[cfg(any(feature = "mceliece348864", feature = "mceliece348864f"))]
 pub(crate) fn transpose_64x64_inplace(arg: &mut [u64; 64]) {
     let masks = [
         [0x5555555555555555u64, 0xAAAAAAAAAAAAAAAAu64],
@@ -76,6 +73,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::needless_range_loop)]
     fn test_transpose() {
         let mut testcases: [TestMatrix; 2] = [
             TestMatrix {
@@ -358,19 +356,13 @@ mod tests {
         };
 
         for i in 0..2 {
-            #[cfg(not(any(feature = "mceliece348864", feature = "mceliece348864f")))]
-            {
-                let mut test_output: [u64; 64] = [0; 64];
-                transpose(&mut test_output, testcases[i].input);
-                assert_eq!(test_output, testcases[i].output);
-            }
+            let mut test_output: [u64; 64] = [0; 64];
+            transpose(&mut test_output, testcases[i].input);
+            assert_eq!(test_output, testcases[i].output);
 
-            #[cfg(any(feature = "mceliece348864", feature = "mceliece348864f"))]
-            {
-                let mut data = testcases[i].input;
-                transpose_64x64_inplace(&mut data);
-                assert_eq!(data, testcases[i].output);
-            }
+            let mut data = testcases[i].input;
+            transpose_64x64_inplace(&mut data);
+            assert_eq!(data, testcases[i].output);
         }
     }
 }
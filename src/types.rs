@@ -0,0 +1,236 @@
+//! Typed wrappers for the byte arrays used by the KEM API
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+use core::fmt;
+
+#[cfg(feature = "zeroize")]
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+#[cfg(feature = "alloc")]
+use super::{
+    operations::public_key_fingerprint, util::heap_array, CRYPTO_PUBLICKEYBYTES,
+    CRYPTO_SECRETKEYBYTES,
+};
+use super::{CRYPTO_BYTES, CRYPTO_CIPHERTEXTBYTES};
+use crate::error::{as_array, Error};
+#[cfg(feature = "alloc")]
+use crate::{fingerprint::Fingerprint, macros::sub};
+
+#[cfg(feature = "alloc")]
+/// A public key of `CRYPTO_PUBLICKEYBYTES` bytes.
+/// Public keys are large, hence the data is stored on the heap.
+#[derive(Clone, PartialEq, Eq)]
+pub struct PublicKey(pub(crate) Box<[u8; CRYPTO_PUBLICKEYBYTES]>);
+
+#[cfg(feature = "alloc")]
+/// A secret key of `CRYPTO_SECRETKEYBYTES` bytes, stored on the heap
+#[derive(Clone)]
+pub struct SecretKey(pub(crate) Box<[u8; CRYPTO_SECRETKEYBYTES]>);
+
+/// A ciphertext of `CRYPTO_CIPHERTEXTBYTES` bytes
+#[derive(Clone, PartialEq, Eq)]
+pub struct Ciphertext(pub(crate) [u8; CRYPTO_CIPHERTEXTBYTES]);
+
+/// A shared secret of `CRYPTO_BYTES` bytes
+#[derive(Clone)]
+pub struct SharedSecret(pub(crate) [u8; CRYPTO_BYTES]);
+
+#[cfg(feature = "alloc")]
+impl PublicKey {
+    /// Returns a public key with all bytes set to zero, allocated directly on the heap
+    pub(crate) fn zeroed() -> PublicKey {
+        PublicKey(heap_array(0u8))
+    }
+
+    /// Copies the public key from `bytes`, which must have length `CRYPTO_PUBLICKEYBYTES`
+    pub fn from_bytes(bytes: &[u8]) -> Result<PublicKey, Error> {
+        let src = as_array::<CRYPTO_PUBLICKEYBYTES>(bytes)?;
+        let mut pk = PublicKey::zeroed();
+        pk.0.copy_from_slice(src);
+        Ok(pk)
+    }
+
+    /// Returns the bytes of the public key
+    pub fn as_bytes(&self) -> &[u8; CRYPTO_PUBLICKEYBYTES] {
+        &self.0
+    }
+
+    /// Returns the fingerprint of the public key, see `public_key_fingerprint`
+    pub fn fingerprint(&self) -> Fingerprint {
+        public_key_fingerprint(&self.0)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl SecretKey {
+    /// Returns a secret key with all bytes set to zero, allocated directly on the heap
+    pub(crate) fn zeroed() -> SecretKey {
+        SecretKey(heap_array(0u8))
+    }
+
+    /// Copies the secret key from `bytes`, which must have length `CRYPTO_SECRETKEYBYTES`
+    pub fn from_bytes(bytes: &[u8]) -> Result<SecretKey, Error> {
+        let src = as_array::<CRYPTO_SECRETKEYBYTES>(bytes)?;
+        let mut sk = SecretKey::zeroed();
+        sk.0.copy_from_slice(src);
+        Ok(sk)
+    }
+
+    /// Returns the bytes of the secret key
+    pub fn as_bytes(&self) -> &[u8; CRYPTO_SECRETKEYBYTES] {
+        &self.0
+    }
+
+    /// Returns the seed of the secret key, the compact form accepted by `expand_secret_key`
+    pub fn seed(&self) -> &[u8; 32] {
+        sub!(self.0, 0, 32)
+    }
+}
+
+impl Ciphertext {
+    /// Copies the ciphertext from `bytes`, which must have length `CRYPTO_CIPHERTEXTBYTES`
+    pub fn from_bytes(bytes: &[u8]) -> Result<Ciphertext, Error> {
+        Ok(Ciphertext(*as_array(bytes)?))
+    }
+
+    /// Returns the bytes of the ciphertext
+    pub fn as_bytes(&self) -> &[u8; CRYPTO_CIPHERTEXTBYTES] {
+        &self.0
+    }
+}
+
+impl SharedSecret {
+    /// Copies the shared secret from `bytes`, which must have length `CRYPTO_BYTES`
+    pub fn from_bytes(bytes: &[u8]) -> Result<SharedSecret, Error> {
+        Ok(SharedSecret(*as_array(bytes)?))
+    }
+
+    /// Returns the bytes of the shared secret
+    pub fn as_bytes(&self) -> &[u8; CRYPTO_BYTES] {
+        &self.0
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "zeroize"))]
+impl Zeroize for SecretKey {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "zeroize"))]
+impl Drop for SecretKey {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "zeroize"))]
+impl ZeroizeOnDrop for SecretKey {}
+
+#[cfg(feature = "zeroize")]
+impl Zeroize for SharedSecret {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for SharedSecret {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl ZeroizeOnDrop for SharedSecret {}
+
+#[cfg(feature = "alloc")]
+impl fmt::Debug for PublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "PublicKey({} bytes)", CRYPTO_PUBLICKEYBYTES)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Debug for SecretKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SecretKey(<redacted>)")
+    }
+}
+
+impl fmt::Debug for Ciphertext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Ciphertext").field(&&self.0[..]).finish()
+    }
+}
+
+impl fmt::Debug for SharedSecret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SharedSecret(<redacted>)")
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_bytes_lengths() {
+        assert!(PublicKey::from_bytes(&vec![0u8; CRYPTO_PUBLICKEYBYTES]).is_ok());
+        assert!(PublicKey::from_bytes(&vec![0u8; CRYPTO_PUBLICKEYBYTES - 1]).is_err());
+        assert!(SecretKey::from_bytes(&[0u8; CRYPTO_SECRETKEYBYTES]).is_ok());
+        assert!(SecretKey::from_bytes(&[0u8; CRYPTO_SECRETKEYBYTES + 1]).is_err());
+        assert!(Ciphertext::from_bytes(&[0u8; CRYPTO_CIPHERTEXTBYTES]).is_ok());
+        assert!(Ciphertext::from_bytes(&[0u8; CRYPTO_BYTES]).is_err());
+        assert!(SharedSecret::from_bytes(&[0u8; CRYPTO_BYTES]).is_ok());
+        assert_eq!(
+            SharedSecret::from_bytes(&[]).err(),
+            Some(Error::InvalidLength {
+                expected: CRYPTO_BYTES,
+                actual: 0
+            })
+        );
+    }
+
+    #[test]
+    fn test_public_key_fingerprint() {
+        let pk = PublicKey::from_bytes(&vec![0x11u8; CRYPTO_PUBLICKEYBYTES]).unwrap();
+        let fingerprint = pk.fingerprint();
+
+        assert_eq!(fingerprint, public_key_fingerprint(pk.as_bytes()));
+        assert_eq!(fingerprint.to_hex().parse(), Ok(fingerprint));
+        assert_ne!(PublicKey::zeroed().fingerprint(), fingerprint);
+    }
+
+    #[test]
+    fn test_debug_redacts_secrets() {
+        let sk = SecretKey::from_bytes(&[0xAB; CRYPTO_SECRETKEYBYTES]).unwrap();
+        let ss = SharedSecret([0xAB; CRYPTO_BYTES]);
+
+        assert_eq!(format!("{:?}", sk), "SecretKey(<redacted>)");
+        assert_eq!(format!("{:?}", ss), "SharedSecret(<redacted>)");
+        assert_eq!(
+            format!("{:?}", PublicKey::zeroed()),
+            format!("PublicKey({} bytes)", CRYPTO_PUBLICKEYBYTES)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "zeroize")]
+    fn test_zeroize_secrets() {
+        fn assert_zeroize_on_drop<T: ZeroizeOnDrop>(_: &T) {}
+
+        let mut sk = SecretKey::from_bytes(&[0xAB; CRYPTO_SECRETKEYBYTES]).unwrap();
+        let mut ss = SharedSecret([0xAB; CRYPTO_BYTES]);
+        assert_zeroize_on_drop(&sk);
+        assert_zeroize_on_drop(&ss);
+
+        sk.zeroize();
+        ss.zeroize();
+
+        assert_eq!(sk.as_bytes(), &[0u8; CRYPTO_SECRETKEYBYTES]);
+        assert_eq!(ss.as_bytes(), &[0u8; CRYPTO_BYTES]);
+    }
+}
@@ -103,17 +103,17 @@ mod tests {
 
             let (x, y) = uint64_minmax(x, y);
 
-            if x > y {
-                assert!(
-                    false,
-                    "erroneous behaviour with inputs: x: 0x{:016X}u64 y: 0x{:016X}u64",
-                    x, y
-                );
-            }
+            assert!(
+                x <= y,
+                "erroneous behaviour with inputs: x: 0x{:016X}u64 y: 0x{:016X}u64",
+                x,
+                y
+            );
         }
     }
 
     #[test]
+    #[allow(clippy::needless_range_loop)]
     fn test_uint64_sort_random_numbers() {
         let mut array: [u64; 64] = [0; 64];
 
@@ -1,6 +1,13 @@
-//! This file is for loading/storing data in a little-endian fashion and a `bitrev` function
+//! This file is for loading/storing data in a little-endian fashion, a `bitrev` function
+//! and the allocation of large arrays
 
-use crate::{gf::Gf, params::GFMASK};
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, vec};
+
+use super::{
+    gf::Gf,
+    params::{GFBITS, GFMASK},
+};
 
 /// Store Gf element `a` in array `dest`
 pub(crate) fn store_gf(dest: &mut [u8; 2], a: Gf) {
@@ -62,16 +69,36 @@ pub(crate) fn bitrev(mut a: Gf) -> Gf {
     a = ((a & 0x3333) << 2) | ((a & 0xCCCC) >> 2);
     a = ((a & 0x5555) << 1) | ((a & 0xAAAA) >> 1);
 
-    #[cfg(any(feature = "mceliece348864", feature = "mceliece348864f"))]
-    {
-        a >> 4
-    }
-    #[cfg(not(any(feature = "mceliece348864", feature = "mceliece348864f")))]
-    {
-        a >> 3
+    a >> (16 - GFBITS)
+}
+
+/// Allocate an array of `N` copies of `value` on the heap.
+/// Unlike `Box::new([value; N])`, the array is never placed on the stack.
+#[cfg(feature = "alloc")]
+pub(crate) fn heap_array<T: Clone, const N: usize>(value: T) -> Box<[T; N]> {
+    match <Box<[T; N]>>::try_from(vec![value; N].into_boxed_slice()) {
+        Ok(array) => array,
+        Err(_) => unreachable!("vector has length N"),
     }
 }
 
+/// An array too large for small stacks. It lives on the heap if the `alloc` feature is enabled.
+#[cfg(feature = "alloc")]
+pub(crate) type LargeArray<T, const N: usize> = Box<[T; N]>;
+
+/// An array too large for small stacks. It lives on the heap if the `alloc` feature is enabled.
+#[cfg(not(feature = "alloc"))]
+pub(crate) type LargeArray<T, const N: usize> = [T; N];
+
+/// Create a `LargeArray` of `N` copies of `value`
+pub(crate) fn large_array<T: Clone, const N: usize>(value: T) -> LargeArray<T, N> {
+    #[cfg(feature = "alloc")]
+    return heap_array(value);
+
+    #[cfg(not(feature = "alloc"))]
+    return core::array::from_fn(|_| value.clone());
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -82,16 +109,13 @@ mod tests {
     }
 
     #[test]
-    #[cfg(any(feature = "mceliece348864", feature = "mceliece348864f"))]
-    fn test_bitrev() {
-        assert_eq!(bitrev(0b1011_0111_0111_1011), 0b0000_1101_1110_1110);
-        assert_eq!(bitrev(0b0110_1010_0101_1011), 0b0000_1101_1010_0101);
-    }
-
-    #[test]
-    #[cfg(not(any(feature = "mceliece348864", feature = "mceliece348864f")))]
     fn test_bitrev() {
-        assert_eq!(bitrev(0b1011_0111_0111_1011), 0b0001_1011_1101_1101);
-        assert_eq!(bitrev(0b0110_1010_0101_1011), 0b0001_1011_0100_1010);
+        if GFBITS == 12 {
+            assert_eq!(bitrev(0b1011_0111_0111_1011), 0b0000_1101_1110_1110);
+            assert_eq!(bitrev(0b0110_1010_0101_1011), 0b0000_1101_1010_0101);
+        } else {
+            assert_eq!(bitrev(0b1011_0111_0111_1011), 0b0001_1011_1101_1101);
+            assert_eq!(bitrev(0b0110_1010_0101_1011), 0b0001_1011_0100_1010);
+        }
     }
 }